@@ -0,0 +1,540 @@
+//! Admin CLI for the cmpgn program.
+//!
+//! Sends transactions signed by the local wallet, or only simulates them with `--dry-run`.
+
+mod rpc;
+
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{Args, Parser, Subcommand};
+use cmpgn_client::{
+    accounts::decode,
+    cmpgn::{
+        BugMetadata, Campaign, CollectionAuthority, CreateCollectionArgs, PlayerProgress,
+        RarityTiers, UpdateCollectionArgs,
+    },
+    filters, instructions, pda, ID,
+};
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+
+use crate::rpc::RpcClient;
+
+#[derive(Parser)]
+#[command(version, about = "Manage cmpgn campaigns, collections and bugs")]
+struct Cli {
+    /// RPC URL or moniker: localnet, devnet or mainnet
+    #[arg(
+        short,
+        long,
+        global = true,
+        env = "CMPGN_RPC_URL",
+        default_value = "localnet"
+    )]
+    url: String,
+
+    /// Keypair that signs and pays for transactions [default: ~/.config/solana/id.json]
+    #[arg(short, long, global = true, env = "CMPGN_KEYPAIR")]
+    keypair: Option<PathBuf>,
+
+    /// Simulate transactions and print their logs instead of sending them
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create and inspect campaigns
+    #[command(subcommand)]
+    Campaign(CampaignCommand),
+    /// Create, update and list collections
+    #[command(subcommand)]
+    Collection(CollectionCommand),
+    /// Define the per-bug metadata of a collection
+    #[command(subcommand)]
+    Bug(BugCommand),
+    /// Inspect player progress
+    #[command(subcommand)]
+    Player(PlayerCommand),
+}
+
+#[derive(Subcommand)]
+enum CampaignCommand {
+    /// Initialize a campaign with the wallet as its game authority
+    Init { campaign_id: u8 },
+    /// Print a campaign's settings and completion stats
+    Stats { campaign_id: u8 },
+    /// Replace the wallets allowed to pay for players' transactions
+    SetSponsors {
+        campaign_id: u8,
+        sponsors: Vec<Pubkey>,
+    },
+    /// Choose which attributes minted NFTs carry, as a bitmask that must include the bug ID
+    SetAttributeSchema {
+        campaign_id: u8,
+        attribute_schema: u16,
+    },
+}
+
+#[derive(Subcommand)]
+enum CollectionCommand {
+    /// Create a collection for a campaign under a freshly generated address
+    Create(CreateCollectionCommand),
+    /// Rename a collection or point it at new metadata
+    Update {
+        collection: Pubkey,
+        #[arg(long)]
+        name: Option<String>,
+        #[arg(long)]
+        uri: Option<String>,
+    },
+    /// List the collections of a campaign
+    List { campaign_id: u8 },
+}
+
+#[derive(Args)]
+struct CreateCollectionCommand {
+    campaign_id: u8,
+    #[arg(long)]
+    name: String,
+    #[arg(long)]
+    uri: String,
+    /// Name of completion NFTs, `{bug_id}` is replaced by the bug id
+    #[arg(long)]
+    nft_name: String,
+    /// Metadata URI of completion NFTs, `{bug_id}` is replaced by the bug id
+    #[arg(long)]
+    nft_uri: String,
+    #[arg(long)]
+    master_nft_name: String,
+    #[arg(long)]
+    master_nft_uri: String,
+    #[arg(long)]
+    soulbound: bool,
+    #[arg(long)]
+    revocable: bool,
+}
+
+#[derive(Subcommand)]
+enum BugCommand {
+    /// Set the metadata URI and optional rarity tiers of a bug
+    Set(SetBugCommand),
+    /// Print the metadata of a bug
+    Show { collection: Pubkey, bug_id: u8 },
+}
+
+#[derive(Args)]
+struct SetBugCommand {
+    collection: Pubkey,
+    bug_id: u8,
+    #[arg(long)]
+    uri: String,
+    /// Longest solve, in seconds, that still earns gold
+    #[arg(long, requires_all = ["silver_max_duration", "gold_uri", "silver_uri", "bronze_uri"])]
+    gold_max_duration: Option<i64>,
+    /// Longest solve, in seconds, that still earns silver
+    #[arg(long, requires = "gold_max_duration")]
+    silver_max_duration: Option<i64>,
+    #[arg(long, requires = "gold_max_duration")]
+    gold_uri: Option<String>,
+    #[arg(long, requires = "gold_max_duration")]
+    silver_uri: Option<String>,
+    #[arg(long, requires = "gold_max_duration")]
+    bronze_uri: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum PlayerCommand {
+    /// Print a player's progress, in one campaign or in all of them
+    Progress {
+        player: Pubkey,
+        #[arg(long)]
+        campaign_id: Option<u8>,
+    },
+}
+
+struct Config {
+    rpc: RpcClient,
+    keypair_path: PathBuf,
+    dry_run: bool,
+}
+
+impl Config {
+    fn wallet(&self) -> Result<Keypair> {
+        read_keypair_file(&self.keypair_path)
+            .map_err(|err| anyhow!("failed to read {}: {err}", self.keypair_path.display()))
+    }
+
+    fn execute(
+        &self,
+        wallet: &Keypair,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<()> {
+        let mut all_signers = vec![wallet];
+        all_signers.extend_from_slice(signers);
+
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&wallet.pubkey()),
+            &all_signers,
+            self.rpc.get_latest_blockhash()?,
+        );
+
+        if !self.dry_run {
+            let signature = self.rpc.send_and_confirm_transaction(&transaction)?;
+            println!("Signature: {signature}");
+            return Ok(());
+        }
+
+        let simulation = self.rpc.simulate_transaction(&transaction)?;
+        for log in &simulation.logs {
+            println!("  {log}");
+        }
+        if let Some(err) = simulation.err {
+            bail!("simulation failed: {err}");
+        }
+        match simulation.units_consumed {
+            Some(units) => println!("Simulation succeeded, {units} compute units consumed"),
+            None => println!("Simulation succeeded"),
+        }
+
+        Ok(())
+    }
+
+    fn fetch<T: anchor_lang::AccountDeserialize>(&self, address: &Pubkey, name: &str) -> Result<T> {
+        let data = self
+            .rpc
+            .get_account_data(address)?
+            .ok_or_else(|| anyhow!("{name} {address} not found"))?;
+
+        decode(&data).with_context(|| format!("failed to decode {name} {address}"))
+    }
+}
+
+fn cluster_url(url: &str) -> String {
+    match url {
+        "localnet" | "l" => "http://127.0.0.1:8899",
+        "devnet" | "d" => "https://api.devnet.solana.com",
+        "mainnet" | "m" => "https://api.mainnet-beta.solana.com",
+        url => url,
+    }
+    .to_string()
+}
+
+fn default_keypair_path() -> Result<PathBuf> {
+    let home = std::env::var_os("HOME").ok_or_else(|| anyhow!("HOME is not set"))?;
+
+    Ok(PathBuf::from(home).join(".config/solana/id.json"))
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let config = Config {
+        rpc: RpcClient::new(cluster_url(&cli.url)),
+        keypair_path: match cli.keypair {
+            Some(path) => path,
+            None => default_keypair_path()?,
+        },
+        dry_run: cli.dry_run,
+    };
+
+    match cli.command {
+        Command::Campaign(command) => run_campaign(&config, command),
+        Command::Collection(command) => run_collection(&config, command),
+        Command::Bug(command) => run_bug(&config, command),
+        Command::Player(command) => run_player(&config, command),
+    }
+}
+
+fn run_campaign(config: &Config, command: CampaignCommand) -> Result<()> {
+    match command {
+        CampaignCommand::Init { campaign_id } => {
+            let wallet = config.wallet()?;
+            config.execute(
+                &wallet,
+                &[instructions::initialize(wallet.pubkey(), campaign_id)],
+                &[],
+            )?;
+            println!("Campaign: {}", pda::find_campaign_address(campaign_id).0);
+        }
+        CampaignCommand::Stats { campaign_id } => {
+            let address = pda::find_campaign_address(campaign_id).0;
+            let campaign: Campaign = config.fetch(&address, "campaign")?;
+            print_campaign(&address, &campaign);
+        }
+        CampaignCommand::SetSponsors {
+            campaign_id,
+            sponsors,
+        } => {
+            let wallet = config.wallet()?;
+            config.execute(
+                &wallet,
+                &[instructions::set_sponsors(
+                    wallet.pubkey(),
+                    campaign_id,
+                    sponsors,
+                )],
+                &[],
+            )?;
+        }
+        CampaignCommand::SetAttributeSchema {
+            campaign_id,
+            attribute_schema,
+        } => {
+            let wallet = config.wallet()?;
+            config.execute(
+                &wallet,
+                &[instructions::set_attribute_schema(
+                    wallet.pubkey(),
+                    campaign_id,
+                    attribute_schema,
+                )],
+                &[],
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn run_collection(config: &Config, command: CollectionCommand) -> Result<()> {
+    match command {
+        CollectionCommand::Create(command) => {
+            let wallet = config.wallet()?;
+            let collection = Keypair::new();
+            let args = CreateCollectionArgs {
+                name: command.name,
+                uri: command.uri,
+                nft_name: command.nft_name,
+                nft_uri: command.nft_uri,
+                master_nft_name: command.master_nft_name,
+                master_nft_uri: command.master_nft_uri,
+                soulbound: command.soulbound,
+                revocable: command.revocable,
+                royalties: None,
+            };
+
+            config.execute(
+                &wallet,
+                &[instructions::create_collection(
+                    wallet.pubkey(),
+                    collection.pubkey(),
+                    command.campaign_id,
+                    args,
+                )],
+                &[&collection],
+            )?;
+            println!("Collection: {}", collection.pubkey());
+        }
+        CollectionCommand::Update {
+            collection,
+            name,
+            uri,
+        } => {
+            if name.is_none() && uri.is_none() {
+                bail!("nothing to update, pass --name and/or --uri");
+            }
+
+            let wallet = config.wallet()?;
+            config.execute(
+                &wallet,
+                &[instructions::update_collection(
+                    wallet.pubkey(),
+                    collection,
+                    UpdateCollectionArgs {
+                        new_name: name,
+                        new_uri: uri,
+                    },
+                )],
+                &[],
+            )?;
+        }
+        CollectionCommand::List { campaign_id } => {
+            let collections = config
+                .rpc
+                .get_program_accounts(&ID, &filters::collections_by_campaign(campaign_id))?;
+
+            if collections.is_empty() {
+                println!("No collections in campaign {campaign_id}");
+            }
+            for (_, data) in collections {
+                let authority: CollectionAuthority = decode(&data)?;
+                print_collection(&authority);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn run_bug(config: &Config, command: BugCommand) -> Result<()> {
+    match command {
+        BugCommand::Set(command) => {
+            let rarity_tiers = match command.gold_max_duration {
+                Some(gold_max_duration) => Some(RarityTiers {
+                    gold_max_duration,
+                    silver_max_duration: command.silver_max_duration.unwrap_or_default(),
+                    gold_uri: command.gold_uri.unwrap_or_default(),
+                    silver_uri: command.silver_uri.unwrap_or_default(),
+                    bronze_uri: command.bronze_uri.unwrap_or_default(),
+                }),
+                None => None,
+            };
+
+            let wallet = config.wallet()?;
+            config.execute(
+                &wallet,
+                &[instructions::set_bug_metadata(
+                    wallet.pubkey(),
+                    command.collection,
+                    command.bug_id,
+                    command.uri,
+                    rarity_tiers,
+                )],
+                &[],
+            )?;
+        }
+        BugCommand::Show { collection, bug_id } => {
+            let address = pda::find_bug_metadata_address(&collection, bug_id).0;
+            let metadata: BugMetadata = config.fetch(&address, "bug metadata")?;
+
+            println!("Bug {} of {}", metadata.bug_id, metadata.collection);
+            println!("  URI: {}", metadata.uri);
+            match metadata.rarity_tiers {
+                Some(tiers) => {
+                    println!(
+                        "  Gold:   up to {}s, {}",
+                        tiers.gold_max_duration, tiers.gold_uri
+                    );
+                    println!(
+                        "  Silver: up to {}s, {}",
+                        tiers.silver_max_duration, tiers.silver_uri
+                    );
+                    println!("  Bronze: {}", tiers.bronze_uri);
+                }
+                None => println!("  Rarity tiers: none"),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn run_player(config: &Config, command: PlayerCommand) -> Result<()> {
+    match command {
+        PlayerCommand::Progress {
+            player,
+            campaign_id: Some(campaign_id),
+        } => {
+            let address = pda::find_player_progress_address(campaign_id, &player).0;
+            let progress: PlayerProgress = config.fetch(&address, "player progress")?;
+            print_progress(&progress);
+        }
+        PlayerCommand::Progress {
+            player,
+            campaign_id: None,
+        } => {
+            let accounts = config
+                .rpc
+                .get_program_accounts(&ID, &filters::progress_by_player(&player))?;
+
+            if accounts.is_empty() {
+                println!("{player} has no progress yet");
+            }
+            for (_, data) in accounts {
+                print_progress(&decode(&data)?);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn print_campaign(address: &Pubkey, campaign: &Campaign) {
+    println!("Campaign {} ({address})", campaign.campaign_id);
+    println!("  Game authority:    {}", campaign.game_authority);
+    println!("  Mint price:        {}", campaign.mint_price);
+    match campaign.payment_mint {
+        Some(payment_mint) => println!("  Payment mint:      {payment_mint}"),
+        None => println!("  Payment mint:      SOL"),
+    }
+    println!("  Attribute schema:  {:#06x}", campaign.attribute_schema);
+    println!("  Collections:       {}", campaign.collections.len());
+    for collection in &campaign.collections {
+        println!("    {collection}");
+    }
+    println!("  Sponsors:          {}", campaign.sponsors.len());
+    for sponsor in &campaign.sponsors {
+        println!("    {sponsor}");
+    }
+    println!("  Total completions: {}", campaign.total_completions);
+    for (index, solvers) in campaign.bug_solvers.iter().enumerate() {
+        if *solvers > 0 {
+            println!("    Bug {:>2}: {solvers} solvers", index + 1);
+        }
+    }
+}
+
+fn print_collection(authority: &CollectionAuthority) {
+    println!("Collection {}", authority.collection);
+    println!("  Creator:    {}", authority.creator);
+    println!(
+        "  NFT:        {} ({})",
+        authority.nft_name, authority.nft_uri
+    );
+    println!(
+        "  Master NFT: {} ({})",
+        authority.master_nft_name, authority.master_nft_uri
+    );
+    println!("  Soulbound:  {}", authority.soulbound);
+    println!("  Revocable:  {}", authority.revocable);
+}
+
+fn print_progress(progress: &PlayerProgress) {
+    println!(
+        "Player {} in campaign {}",
+        progress.player, progress.campaign_id
+    );
+    println!("  Completed bugs: {:?}", progress.completed_bugs);
+    println!("  Total score:    {}", progress.total_score);
+    match progress.master_mint_address {
+        Some(master) => println!("  Master NFT:     {master}"),
+        None => println!("  Master NFT:     not minted"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+
+    use super::*;
+
+    #[test]
+    fn cli_definition_is_valid() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn cluster_monikers_resolve_to_urls() {
+        assert_eq!(cluster_url("localnet"), "http://127.0.0.1:8899");
+        assert_eq!(cluster_url("d"), "https://api.devnet.solana.com");
+        assert_eq!(
+            cluster_url("mainnet"),
+            "https://api.mainnet-beta.solana.com"
+        );
+        assert_eq!(
+            cluster_url("https://rpc.example.com"),
+            "https://rpc.example.com"
+        );
+    }
+}
@@ -0,0 +1,223 @@
+//! Just enough of the Solana JSON-RPC API for the admin commands.
+
+use std::{str::FromStr, thread, time::Duration};
+
+use anyhow::{anyhow, bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use cmpgn_client::filters::AccountFilter;
+use serde_json::{json, Value};
+use solana_sdk::{hash::Hash, pubkey::Pubkey, signature::Signature, transaction::Transaction};
+
+const CONFIRMATION_ATTEMPTS: usize = 60;
+const CONFIRMATION_INTERVAL: Duration = Duration::from_millis(500);
+
+pub struct Simulation {
+    pub err: Option<Value>,
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+}
+
+pub struct RpcClient {
+    url: String,
+    agent: ureq::Agent,
+}
+
+impl RpcClient {
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            agent: ureq::agent(),
+        }
+    }
+
+    fn request(&self, method: &str, params: Value) -> Result<Value> {
+        let mut response: Value = self
+            .agent
+            .post(&self.url)
+            .send_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": method,
+                "params": params,
+            }))
+            .with_context(|| format!("{method} request to {} failed", self.url))?
+            .into_json()
+            .with_context(|| format!("invalid {method} response"))?;
+
+        if let Some(error) = response.get("error") {
+            bail!("{method} failed: {error}");
+        }
+
+        Ok(response["result"].take())
+    }
+
+    pub fn get_latest_blockhash(&self) -> Result<Hash> {
+        let result = self.request("getLatestBlockhash", json!([{ "commitment": "confirmed" }]))?;
+        let blockhash = result["value"]["blockhash"]
+            .as_str()
+            .ok_or_else(|| anyhow!("missing blockhash"))?;
+
+        Hash::from_str(blockhash).map_err(|err| anyhow!("invalid blockhash: {err}"))
+    }
+
+    pub fn get_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>> {
+        let result = self.request(
+            "getAccountInfo",
+            json!([
+                address.to_string(),
+                { "encoding": "base64", "commitment": "confirmed" },
+            ]),
+        )?;
+
+        match &result["value"] {
+            Value::Null => Ok(None),
+            account => decode_data(account).map(Some),
+        }
+    }
+
+    pub fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: &[AccountFilter],
+    ) -> Result<Vec<(Pubkey, Vec<u8>)>> {
+        let filters: Vec<Value> = filters.iter().map(filter_to_json).collect();
+
+        let result = self.request(
+            "getProgramAccounts",
+            json!([
+                program_id.to_string(),
+                { "encoding": "base64", "commitment": "confirmed", "filters": filters },
+            ]),
+        )?;
+
+        result
+            .as_array()
+            .ok_or_else(|| anyhow!("invalid getProgramAccounts response"))?
+            .iter()
+            .map(|keyed_account| {
+                let address = keyed_account["pubkey"]
+                    .as_str()
+                    .ok_or_else(|| anyhow!("missing account address"))?;
+                let address = Pubkey::from_str(address)?;
+
+                Ok((address, decode_data(&keyed_account["account"])?))
+            })
+            .collect()
+    }
+
+    pub fn simulate_transaction(&self, transaction: &Transaction) -> Result<Simulation> {
+        let result = self.request(
+            "simulateTransaction",
+            json!([
+                encode_transaction(transaction)?,
+                { "encoding": "base64", "commitment": "confirmed", "sigVerify": true },
+            ]),
+        )?;
+        let value = &result["value"];
+
+        Ok(Simulation {
+            err: Some(value["err"].clone()).filter(|err| !err.is_null()),
+            logs: value["logs"]
+                .as_array()
+                .map(|logs| {
+                    logs.iter()
+                        .filter_map(|log| log.as_str().map(str::to_owned))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            units_consumed: value["unitsConsumed"].as_u64(),
+        })
+    }
+
+    /// Sends the transaction and waits until it is confirmed.
+    pub fn send_and_confirm_transaction(&self, transaction: &Transaction) -> Result<Signature> {
+        let result = self.request(
+            "sendTransaction",
+            json!([
+                encode_transaction(transaction)?,
+                { "encoding": "base64", "preflightCommitment": "confirmed" },
+            ]),
+        )?;
+        let signature = result
+            .as_str()
+            .ok_or_else(|| anyhow!("missing transaction signature"))?;
+
+        for _ in 0..CONFIRMATION_ATTEMPTS {
+            let statuses = self.request("getSignatureStatuses", json!([[signature]]))?;
+            let status = &statuses["value"][0];
+
+            if !status.is_null() {
+                if !status["err"].is_null() {
+                    bail!("transaction {signature} failed: {}", status["err"]);
+                }
+                if matches!(
+                    status["confirmationStatus"].as_str(),
+                    Some("confirmed" | "finalized")
+                ) {
+                    return Ok(Signature::from_str(signature)?);
+                }
+            }
+
+            thread::sleep(CONFIRMATION_INTERVAL);
+        }
+
+        bail!("transaction {signature} was not confirmed in time")
+    }
+}
+
+fn filter_to_json(filter: &AccountFilter) -> Value {
+    match filter {
+        AccountFilter::DataSize(size) => json!({ "dataSize": size }),
+        AccountFilter::Memcmp { offset, bytes } => json!({
+            "memcmp": {
+                "offset": offset,
+                "bytes": STANDARD.encode(bytes),
+                "encoding": "base64",
+            }
+        }),
+    }
+}
+
+fn decode_data(account: &Value) -> Result<Vec<u8>> {
+    let data = account["data"][0]
+        .as_str()
+        .ok_or_else(|| anyhow!("missing account data"))?;
+
+    Ok(STANDARD.decode(data)?)
+}
+
+fn encode_transaction(transaction: &Transaction) -> Result<String> {
+    Ok(STANDARD.encode(bincode::serialize(transaction)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn data_size_filter_to_json() {
+        assert_eq!(
+            filter_to_json(&AccountFilter::DataSize(165)),
+            json!({ "dataSize": 165 })
+        );
+    }
+
+    #[test]
+    fn memcmp_filter_to_json() {
+        let filter = AccountFilter::Memcmp {
+            offset: 40,
+            bytes: vec![1, 2, 3],
+        };
+
+        assert_eq!(
+            filter_to_json(&filter),
+            json!({
+                "memcmp": {
+                    "offset": 40,
+                    "bytes": "AQID",
+                    "encoding": "base64",
+                }
+            })
+        );
+    }
+}
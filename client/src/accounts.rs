@@ -0,0 +1,62 @@
+use anchor_lang::{AccountDeserialize, Discriminator, Result};
+use cmpgn::{
+    Bounty, BountyClaim, BugMetadata, Campaign, CampaignCompletion, CollectionAuthority, DailyBug,
+    ForgeRecipe, PlayerProgress, RewardVault, Treasury,
+};
+
+/// Decodes the data of a single account of a known type.
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+/// Any account owned by the cmpgn program.
+pub enum CmpgnAccount {
+    Bounty(Bounty),
+    BountyClaim(BountyClaim),
+    BugMetadata(BugMetadata),
+    Campaign(Campaign),
+    CampaignCompletion(CampaignCompletion),
+    CollectionAuthority(CollectionAuthority),
+    DailyBug(DailyBug),
+    ForgeRecipe(ForgeRecipe),
+    PlayerProgress(PlayerProgress),
+    RewardVault(RewardVault),
+    Treasury(Treasury),
+}
+
+impl CmpgnAccount {
+    /// Decodes an account of unknown type by its discriminator, e.g. while indexing
+    /// `getProgramAccounts` results.
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let account = match data {
+            data if data.starts_with(Bounty::DISCRIMINATOR) => Self::Bounty(decode(data)?),
+            data if data.starts_with(BountyClaim::DISCRIMINATOR) => {
+                Self::BountyClaim(decode(data)?)
+            }
+            data if data.starts_with(BugMetadata::DISCRIMINATOR) => {
+                Self::BugMetadata(decode(data)?)
+            }
+            data if data.starts_with(Campaign::DISCRIMINATOR) => Self::Campaign(decode(data)?),
+            data if data.starts_with(CampaignCompletion::DISCRIMINATOR) => {
+                Self::CampaignCompletion(decode(data)?)
+            }
+            data if data.starts_with(CollectionAuthority::DISCRIMINATOR) => {
+                Self::CollectionAuthority(decode(data)?)
+            }
+            data if data.starts_with(DailyBug::DISCRIMINATOR) => Self::DailyBug(decode(data)?),
+            data if data.starts_with(ForgeRecipe::DISCRIMINATOR) => {
+                Self::ForgeRecipe(decode(data)?)
+            }
+            data if data.starts_with(PlayerProgress::DISCRIMINATOR) => {
+                Self::PlayerProgress(decode(data)?)
+            }
+            data if data.starts_with(RewardVault::DISCRIMINATOR) => {
+                Self::RewardVault(decode(data)?)
+            }
+            data if data.starts_with(Treasury::DISCRIMINATOR) => Self::Treasury(decode(data)?),
+            _ => return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into()),
+        };
+
+        Ok(account)
+    }
+}
@@ -0,0 +1,242 @@
+//! Filters for `getProgramAccounts`.
+//!
+//! They are plain data so they can be turned into the filter type of whichever RPC client
+//! the caller uses. Offsets skip the 8 byte account discriminator.
+
+use anchor_lang::{prelude::Pubkey, Discriminator};
+use cmpgn::{Bounty, BountyClaim, CampaignCompletion, CollectionAuthority, PlayerProgress};
+
+const DISCRIMINATOR_LEN: usize = 8;
+const PUBKEY_LEN: usize = 32;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccountFilter {
+    DataSize(u64),
+    Memcmp { offset: usize, bytes: Vec<u8> },
+}
+
+/// Matches every account of type `T`.
+pub fn account_type<T: Discriminator>() -> AccountFilter {
+    AccountFilter::Memcmp {
+        offset: 0,
+        bytes: T::DISCRIMINATOR.to_vec(),
+    }
+}
+
+fn pubkey_at(offset: usize, pubkey: &Pubkey) -> AccountFilter {
+    AccountFilter::Memcmp {
+        offset,
+        bytes: pubkey.to_bytes().to_vec(),
+    }
+}
+
+fn campaign_id_at(offset: usize, campaign_id: u8) -> AccountFilter {
+    AccountFilter::Memcmp {
+        offset,
+        bytes: vec![campaign_id],
+    }
+}
+
+/// All completions of `player`, across campaigns.
+pub fn completions_by_player(player: &Pubkey) -> Vec<AccountFilter> {
+    vec![
+        account_type::<CampaignCompletion>(),
+        pubkey_at(DISCRIMINATOR_LEN, player),
+    ]
+}
+
+/// All completions recorded in the campaign `campaign_id`.
+pub fn completions_by_campaign(campaign_id: u8) -> Vec<AccountFilter> {
+    vec![
+        account_type::<CampaignCompletion>(),
+        campaign_id_at(DISCRIMINATOR_LEN + PUBKEY_LEN, campaign_id),
+    ]
+}
+
+/// All progress accounts of `player`, one per campaign played.
+pub fn progress_by_player(player: &Pubkey) -> Vec<AccountFilter> {
+    vec![
+        account_type::<PlayerProgress>(),
+        pubkey_at(DISCRIMINATOR_LEN, player),
+    ]
+}
+
+/// All player progress accounts of the campaign `campaign_id`.
+pub fn progress_by_campaign(campaign_id: u8) -> Vec<AccountFilter> {
+    vec![
+        account_type::<PlayerProgress>(),
+        campaign_id_at(DISCRIMINATOR_LEN + PUBKEY_LEN, campaign_id),
+    ]
+}
+
+/// All collections bound to the campaign `campaign_id`.
+pub fn collections_by_campaign(campaign_id: u8) -> Vec<AccountFilter> {
+    vec![
+        account_type::<CollectionAuthority>(),
+        campaign_id_at(DISCRIMINATOR_LEN + 2 * PUBKEY_LEN, campaign_id),
+    ]
+}
+
+/// All bounties funded by `sponsor`.
+pub fn bounties_by_sponsor(sponsor: &Pubkey) -> Vec<AccountFilter> {
+    vec![
+        account_type::<Bounty>(),
+        pubkey_at(DISCRIMINATOR_LEN, sponsor),
+    ]
+}
+
+/// All bounties of the campaign `campaign_id`.
+pub fn bounties_by_campaign(campaign_id: u8) -> Vec<AccountFilter> {
+    vec![
+        account_type::<Bounty>(),
+        campaign_id_at(DISCRIMINATOR_LEN + PUBKEY_LEN, campaign_id),
+    ]
+}
+
+/// All bounty claims made by `player`.
+pub fn bounty_claims_by_player(player: &Pubkey) -> Vec<AccountFilter> {
+    vec![
+        account_type::<BountyClaim>(),
+        pubkey_at(DISCRIMINATOR_LEN + PUBKEY_LEN, player),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::AccountSerialize;
+
+    use super::*;
+
+    const CAMPAIGN_ID: u8 = 7;
+
+    fn serialize<T: AccountSerialize>(account: &T) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    fn matches(filters: &[AccountFilter], data: &[u8]) -> bool {
+        filters.iter().all(|filter| match filter {
+            AccountFilter::DataSize(size) => data.len() as u64 == *size,
+            AccountFilter::Memcmp { offset, bytes } => {
+                data.get(*offset..*offset + bytes.len()) == Some(bytes.as_slice())
+            }
+        })
+    }
+
+    fn completion(player: Pubkey) -> Vec<u8> {
+        serialize(&CampaignCompletion {
+            player,
+            campaign_id: CAMPAIGN_ID,
+            campaign_start: Some(1),
+            campaign_end: Some(2),
+            attempts: 1,
+            timestamp: Some(2),
+            bug_id: 3,
+            nft_mint_address: Some(Pubkey::new_unique()),
+            daily_solve: true,
+            score: 200,
+            solver_number: 1,
+            revoked: false,
+            reward_claimed: false,
+            bump: 255,
+        })
+    }
+
+    fn progress(player: Pubkey) -> Vec<u8> {
+        serialize(&PlayerProgress {
+            player,
+            campaign_id: CAMPAIGN_ID,
+            completed_bugs: vec![3],
+            total_completed_bugs: 1,
+            total_score: 200,
+            master_mint_address: None,
+            bump: 255,
+        })
+    }
+
+    #[test]
+    fn completion_filters_match_at_their_offsets() {
+        let player = Pubkey::new_unique();
+        let data = completion(player);
+
+        assert!(matches(&completions_by_player(&player), &data));
+        assert!(matches(&completions_by_campaign(CAMPAIGN_ID), &data));
+        assert!(!matches(
+            &completions_by_player(&Pubkey::new_unique()),
+            &data
+        ));
+        assert!(!matches(&completions_by_campaign(CAMPAIGN_ID + 1), &data));
+        assert!(!matches(&progress_by_player(&player), &data));
+    }
+
+    #[test]
+    fn progress_filters_match_at_their_offsets() {
+        let player = Pubkey::new_unique();
+        let data = progress(player);
+
+        assert!(matches(&progress_by_player(&player), &data));
+        assert!(matches(&progress_by_campaign(CAMPAIGN_ID), &data));
+        assert!(!matches(&progress_by_player(&Pubkey::new_unique()), &data));
+        assert!(!matches(&progress_by_campaign(CAMPAIGN_ID + 1), &data));
+        assert!(!matches(&completions_by_player(&player), &data));
+    }
+
+    #[test]
+    fn collection_filters_match_at_their_offsets() {
+        let data = serialize(&CollectionAuthority {
+            creator: Pubkey::new_unique(),
+            collection: Pubkey::new_unique(),
+            campaign_id: CAMPAIGN_ID,
+            nft_name: "Bug #{bug_id}".to_string(),
+            nft_uri: "https://example.com/{bug_id}.json".to_string(),
+            master_nft_name: "Master".to_string(),
+            master_nft_uri: "https://example.com/master.json".to_string(),
+            soulbound: false,
+            revocable: true,
+            bump: 255,
+        });
+
+        assert!(matches(&collections_by_campaign(CAMPAIGN_ID), &data));
+        assert!(!matches(&collections_by_campaign(CAMPAIGN_ID + 1), &data));
+    }
+
+    #[test]
+    fn bounty_filters_match_at_their_offsets() {
+        let sponsor = Pubkey::new_unique();
+        let data = serialize(&Bounty {
+            sponsor,
+            campaign_id: CAMPAIGN_ID,
+            bug_id: 3,
+            nonce: 0,
+            payment_mint: None,
+            amount_per_solver: 1_000,
+            first_solver: 1,
+            max_solvers: 10,
+            claimed: 0,
+            expires_at: 0,
+            bump: 255,
+        });
+
+        assert!(matches(&bounties_by_sponsor(&sponsor), &data));
+        assert!(matches(&bounties_by_campaign(CAMPAIGN_ID), &data));
+        assert!(!matches(&bounties_by_sponsor(&Pubkey::new_unique()), &data));
+        assert!(!matches(&bounties_by_campaign(CAMPAIGN_ID + 1), &data));
+    }
+
+    #[test]
+    fn bounty_claim_filters_match_at_their_offsets() {
+        let player = Pubkey::new_unique();
+        let data = serialize(&BountyClaim {
+            bounty: Pubkey::new_unique(),
+            player,
+            bump: 255,
+        });
+
+        assert!(matches(&bounty_claims_by_player(&player), &data));
+        assert!(!matches(
+            &bounty_claims_by_player(&Pubkey::new_unique()),
+            &data
+        ));
+    }
+}
@@ -0,0 +1,845 @@
+//! One builder per program instruction.
+//!
+//! Program derived addresses are filled in from the ids passed in, so callers only supply
+//! the keys the program cannot derive itself. Optional accounts left out are encoded the
+//! way Anchor expects, as the program id.
+
+use anchor_lang::{
+    prelude::{AccountMeta, Pubkey},
+    solana_program::{instruction::Instruction, system_program, sysvar},
+    InstructionData, ToAccountMetas,
+};
+use cmpgn::{
+    accounts, instruction, CollectionPluginArgs, CollectionPluginKind, ConfigureRewardsArgs,
+    ConfigureTreasuryArgs, CreateCollectionArgs, ForgeRecipeArgs, FundBountyArgs, NftAttribute,
+    RarityTiers, UpdateCollectionArgs, ID,
+};
+use mpl_core::ID as CORE_PROGRAM_ID;
+
+use crate::pda::{
+    find_bounty_address, find_bounty_claim_address, find_bug_metadata_address,
+    find_campaign_address, find_campaign_completion_address, find_collection_authority_address,
+    find_forge_recipe_address, find_player_progress_address, find_reward_tokens_address,
+    find_reward_vault_address, find_treasury_address,
+};
+
+/// Token accounts for campaigns whose mint price is paid in an SPL token.
+#[derive(Clone, Copy, Debug)]
+pub struct MintFeeTokenAccounts {
+    pub player_token_account: Pubkey,
+    pub treasury_token_account: Pubkey,
+    pub payment_mint: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// How the mint price of a campaign is paid, if it has one.
+#[derive(Clone, Copy, Debug)]
+pub enum MintFee {
+    Sol,
+    Token(MintFeeTokenAccounts),
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MintNftOptions {
+    /// Mint to a wallet other than the player's.
+    pub recipient: Option<Pubkey>,
+    pub fee: Option<MintFee>,
+}
+
+/// Token accounts for treasuries that collect an SPL token.
+#[derive(Clone, Copy, Debug)]
+pub struct TreasuryTokenAccounts {
+    pub treasury_token_account: Pubkey,
+    pub payment_mint: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// Token accounts for bounties paid in an SPL token. `owner_token_account` belongs to the
+/// sponsor when funding or reclaiming and to the player when claiming.
+#[derive(Clone, Copy, Debug)]
+pub struct BountyTokenAccounts {
+    pub owner_token_account: Pubkey,
+    pub escrow_token_account: Pubkey,
+    pub payment_mint: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// The minted NFT to burn when revoking a completion.
+#[derive(Clone, Copy, Debug)]
+pub struct RevokedAsset {
+    pub asset: Pubkey,
+    pub collection: Pubkey,
+}
+
+fn build(
+    accounts: impl ToAccountMetas,
+    remaining_accounts: Vec<AccountMeta>,
+    data: impl InstructionData,
+) -> Instruction {
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.extend(remaining_accounts);
+
+    Instruction {
+        program_id: ID,
+        accounts: account_metas,
+        data: data.data(),
+    }
+}
+
+pub fn initialize(game_authority: Pubkey, campaign_id: u8) -> Instruction {
+    build(
+        accounts::InitializeCampaign {
+            game_authority,
+            campaign: find_campaign_address(campaign_id).0,
+            system_program: system_program::ID,
+        },
+        vec![],
+        instruction::Initialize { campaign_id },
+    )
+}
+
+pub fn configure_treasury(
+    game_authority: Pubkey,
+    campaign_id: u8,
+    args: ConfigureTreasuryArgs,
+) -> Instruction {
+    build(
+        accounts::ConfigureTreasury {
+            game_authority,
+            campaign: find_campaign_address(campaign_id).0,
+            treasury: find_treasury_address(campaign_id).0,
+            system_program: system_program::ID,
+        },
+        vec![],
+        instruction::ConfigureTreasury { campaign_id, args },
+    )
+}
+
+/// `payouts` are the recipient wallets, or their token accounts for SPL treasuries, in the
+/// order the treasury lists its recipients.
+pub fn withdraw_treasury(
+    game_authority: Pubkey,
+    campaign_id: u8,
+    amount: u64,
+    token_accounts: Option<TreasuryTokenAccounts>,
+    payouts: &[Pubkey],
+) -> Instruction {
+    build(
+        accounts::WithdrawTreasury {
+            game_authority,
+            campaign: find_campaign_address(campaign_id).0,
+            treasury: find_treasury_address(campaign_id).0,
+            treasury_token_account: token_accounts.map(|t| t.treasury_token_account),
+            payment_mint: token_accounts.map(|t| t.payment_mint),
+            token_program: token_accounts.map(|t| t.token_program),
+            system_program: system_program::ID,
+        },
+        payouts
+            .iter()
+            .map(|payout| AccountMeta::new(*payout, false))
+            .collect(),
+        instruction::WithdrawTreasury {
+            campaign_id,
+            amount,
+        },
+    )
+}
+
+/// `authority_token_account` is only needed when `args.deposit` is non-zero.
+pub fn configure_rewards(
+    game_authority: Pubkey,
+    campaign_id: u8,
+    reward_mint: Pubkey,
+    token_program: Pubkey,
+    authority_token_account: Option<Pubkey>,
+    args: ConfigureRewardsArgs,
+) -> Instruction {
+    build(
+        accounts::ConfigureRewards {
+            game_authority,
+            campaign: find_campaign_address(campaign_id).0,
+            reward_mint,
+            reward_vault: find_reward_vault_address(campaign_id).0,
+            vault_token_account: find_reward_tokens_address(campaign_id).0,
+            authority_token_account,
+            token_program,
+            system_program: system_program::ID,
+        },
+        vec![],
+        instruction::ConfigureRewards { campaign_id, args },
+    )
+}
+
+pub fn set_sponsors(game_authority: Pubkey, campaign_id: u8, sponsors: Vec<Pubkey>) -> Instruction {
+    build(
+        accounts::SetSponsors {
+            game_authority,
+            campaign: find_campaign_address(campaign_id).0,
+        },
+        vec![],
+        instruction::SetSponsors {
+            campaign_id,
+            sponsors,
+        },
+    )
+}
+
+pub fn set_attribute_schema(
+    game_authority: Pubkey,
+    campaign_id: u8,
+    attribute_schema: u16,
+) -> Instruction {
+    build(
+        accounts::SetAttributeSchema {
+            game_authority,
+            campaign: find_campaign_address(campaign_id).0,
+        },
+        vec![],
+        instruction::SetAttributeSchema {
+            campaign_id,
+            attribute_schema,
+        },
+    )
+}
+
+pub fn create_collection(
+    creator: Pubkey,
+    collection: Pubkey,
+    campaign_id: u8,
+    args: CreateCollectionArgs,
+) -> Instruction {
+    build(
+        accounts::CreateCollection {
+            creator,
+            collection,
+            collection_authority: find_collection_authority_address(&collection).0,
+            campaign: find_campaign_address(campaign_id).0,
+            core_program: CORE_PROGRAM_ID,
+            system_program: system_program::ID,
+        },
+        vec![],
+        instruction::CreateCollection { campaign_id, args },
+    )
+}
+
+pub fn update_collection(
+    creator: Pubkey,
+    collection: Pubkey,
+    args: UpdateCollectionArgs,
+) -> Instruction {
+    build(
+        accounts::UpdateCollection {
+            creator,
+            collection,
+            collection_authority: find_collection_authority_address(&collection).0,
+            core_program: CORE_PROGRAM_ID,
+            system_program: system_program::ID,
+        },
+        vec![],
+        instruction::UpdateCollection { args },
+    )
+}
+
+pub fn add_collection_plugin(
+    creator: Pubkey,
+    collection: Pubkey,
+    plugin: CollectionPluginArgs,
+) -> Instruction {
+    build(
+        accounts::AddCollectionPlugin {
+            creator,
+            collection,
+            collection_authority: find_collection_authority_address(&collection).0,
+            core_program: CORE_PROGRAM_ID,
+            system_program: system_program::ID,
+        },
+        vec![],
+        instruction::AddCollectionPlugin { plugin },
+    )
+}
+
+pub fn remove_collection_plugin(
+    creator: Pubkey,
+    collection: Pubkey,
+    plugin: CollectionPluginKind,
+) -> Instruction {
+    build(
+        accounts::RemoveCollectionPlugin {
+            creator,
+            collection,
+            collection_authority: find_collection_authority_address(&collection).0,
+            core_program: CORE_PROGRAM_ID,
+            system_program: system_program::ID,
+        },
+        vec![],
+        instruction::RemoveCollectionPlugin { plugin },
+    )
+}
+
+pub fn set_bug_metadata(
+    creator: Pubkey,
+    collection: Pubkey,
+    bug_id: u8,
+    uri: String,
+    rarity_tiers: Option<RarityTiers>,
+) -> Instruction {
+    build(
+        accounts::SetBugMetadata {
+            creator,
+            collection,
+            collection_authority: find_collection_authority_address(&collection).0,
+            bug_metadata: find_bug_metadata_address(&collection, bug_id).0,
+            system_program: system_program::ID,
+        },
+        vec![],
+        instruction::SetBugMetadata {
+            bug_id,
+            uri,
+            rarity_tiers,
+        },
+    )
+}
+
+pub fn start_campaign(
+    player: Pubkey,
+    payer: Option<Pubkey>,
+    campaign_id: u8,
+    bug_id: u8,
+) -> Instruction {
+    build(
+        accounts::StartCampaign {
+            player,
+            payer,
+            campaign_completion: find_campaign_completion_address(campaign_id, &player, bug_id).0,
+            campaign: find_campaign_address(campaign_id).0,
+            system_program: system_program::ID,
+        },
+        vec![],
+        instruction::StartCampaign {
+            campaign_id,
+            bug_id,
+        },
+    )
+}
+
+pub fn record_campaign_completion(
+    player: Pubkey,
+    payer: Option<Pubkey>,
+    campaign_id: u8,
+    bug_id: u8,
+) -> Instruction {
+    build(
+        accounts::RecordCampaignCompletion {
+            player,
+            payer,
+            campaign_completion: find_campaign_completion_address(campaign_id, &player, bug_id).0,
+            player_progress: find_player_progress_address(campaign_id, &player).0,
+            campaign: find_campaign_address(campaign_id).0,
+            system_program: system_program::ID,
+        },
+        vec![],
+        instruction::RecordCampaignCompletion {
+            campaign_id,
+            bug_id,
+        },
+    )
+}
+
+fn fee_accounts(
+    campaign_id: u8,
+    fee: Option<MintFee>,
+) -> (Option<Pubkey>, Option<MintFeeTokenAccounts>) {
+    let treasury = fee.map(|_| find_treasury_address(campaign_id).0);
+    let token_accounts = match fee {
+        Some(MintFee::Token(token_accounts)) => Some(token_accounts),
+        _ => None,
+    };
+
+    (treasury, token_accounts)
+}
+
+pub fn mint_nft(
+    player: Pubkey,
+    payer: Option<Pubkey>,
+    asset: Pubkey,
+    collection: Pubkey,
+    campaign_id: u8,
+    bug_id: u8,
+    options: MintNftOptions,
+) -> Instruction {
+    let (treasury, token_accounts) = fee_accounts(campaign_id, options.fee);
+
+    build(
+        accounts::MintNft {
+            player,
+            payer,
+            asset,
+            collection,
+            collection_authority: find_collection_authority_address(&collection).0,
+            campaign: find_campaign_address(campaign_id).0,
+            bug_metadata: find_bug_metadata_address(&collection, bug_id).0,
+            campaign_completion: find_campaign_completion_address(campaign_id, &player, bug_id).0,
+            recipient: options.recipient,
+            treasury,
+            player_token_account: token_accounts.map(|t| t.player_token_account),
+            treasury_token_account: token_accounts.map(|t| t.treasury_token_account),
+            payment_mint: token_accounts.map(|t| t.payment_mint),
+            token_program: token_accounts.map(|t| t.token_program),
+            core_program: CORE_PROGRAM_ID,
+            system_program: system_program::ID,
+        },
+        vec![],
+        instruction::MintNft {
+            campaign_id,
+            bug_id,
+        },
+    )
+}
+
+pub fn complete_and_mint(
+    player: Pubkey,
+    payer: Option<Pubkey>,
+    asset: Pubkey,
+    collection: Pubkey,
+    campaign_id: u8,
+    bug_id: u8,
+    options: MintNftOptions,
+) -> Instruction {
+    let (treasury, token_accounts) = fee_accounts(campaign_id, options.fee);
+
+    build(
+        accounts::CompleteAndMint {
+            player,
+            payer,
+            asset,
+            collection,
+            collection_authority: find_collection_authority_address(&collection).0,
+            campaign: find_campaign_address(campaign_id).0,
+            bug_metadata: find_bug_metadata_address(&collection, bug_id).0,
+            campaign_completion: find_campaign_completion_address(campaign_id, &player, bug_id).0,
+            player_progress: find_player_progress_address(campaign_id, &player).0,
+            recipient: options.recipient,
+            treasury,
+            player_token_account: token_accounts.map(|t| t.player_token_account),
+            treasury_token_account: token_accounts.map(|t| t.treasury_token_account),
+            payment_mint: token_accounts.map(|t| t.payment_mint),
+            token_program: token_accounts.map(|t| t.token_program),
+            core_program: CORE_PROGRAM_ID,
+            system_program: system_program::ID,
+        },
+        vec![],
+        instruction::CompleteAndMint {
+            campaign_id,
+            bug_id,
+        },
+    )
+}
+
+pub fn claim_reward(
+    player: Pubkey,
+    campaign_id: u8,
+    bug_id: u8,
+    reward_mint: Pubkey,
+    player_token_account: Pubkey,
+    token_program: Pubkey,
+) -> Instruction {
+    build(
+        accounts::ClaimReward {
+            player,
+            campaign_completion: find_campaign_completion_address(campaign_id, &player, bug_id).0,
+            reward_vault: find_reward_vault_address(campaign_id).0,
+            reward_mint,
+            vault_token_account: find_reward_tokens_address(campaign_id).0,
+            player_token_account,
+            token_program,
+        },
+        vec![],
+        instruction::ClaimReward {
+            campaign_id,
+            bug_id,
+        },
+    )
+}
+
+pub fn fund_bounty(
+    sponsor: Pubkey,
+    campaign_id: u8,
+    bug_id: u8,
+    args: FundBountyArgs,
+    token_accounts: Option<BountyTokenAccounts>,
+) -> Instruction {
+    build(
+        accounts::FundBounty {
+            sponsor,
+            campaign: find_campaign_address(campaign_id).0,
+            bounty: find_bounty_address(campaign_id, bug_id, &sponsor, args.nonce).0,
+            sponsor_token_account: token_accounts.map(|t| t.owner_token_account),
+            escrow_token_account: token_accounts.map(|t| t.escrow_token_account),
+            payment_mint: token_accounts.map(|t| t.payment_mint),
+            token_program: token_accounts.map(|t| t.token_program),
+            system_program: system_program::ID,
+        },
+        vec![],
+        instruction::FundBounty {
+            campaign_id,
+            bug_id,
+            args,
+        },
+    )
+}
+
+pub fn claim_bounty(
+    player: Pubkey,
+    sponsor: Pubkey,
+    campaign_id: u8,
+    bug_id: u8,
+    nonce: u32,
+    token_accounts: Option<BountyTokenAccounts>,
+) -> Instruction {
+    let bounty = find_bounty_address(campaign_id, bug_id, &sponsor, nonce).0;
+
+    build(
+        accounts::ClaimBounty {
+            player,
+            campaign_completion: find_campaign_completion_address(campaign_id, &player, bug_id).0,
+            bounty,
+            bounty_claim: find_bounty_claim_address(&bounty, &player).0,
+            escrow_token_account: token_accounts.map(|t| t.escrow_token_account),
+            player_token_account: token_accounts.map(|t| t.owner_token_account),
+            payment_mint: token_accounts.map(|t| t.payment_mint),
+            token_program: token_accounts.map(|t| t.token_program),
+            system_program: system_program::ID,
+        },
+        vec![],
+        instruction::ClaimBounty {
+            campaign_id,
+            bug_id,
+        },
+    )
+}
+
+pub fn reclaim_bounty(
+    sponsor: Pubkey,
+    campaign_id: u8,
+    bug_id: u8,
+    nonce: u32,
+    token_accounts: Option<BountyTokenAccounts>,
+) -> Instruction {
+    build(
+        accounts::ReclaimBounty {
+            sponsor,
+            bounty: find_bounty_address(campaign_id, bug_id, &sponsor, nonce).0,
+            escrow_token_account: token_accounts.map(|t| t.escrow_token_account),
+            sponsor_token_account: token_accounts.map(|t| t.owner_token_account),
+            payment_mint: token_accounts.map(|t| t.payment_mint),
+            token_program: token_accounts.map(|t| t.token_program),
+        },
+        vec![],
+        instruction::ReclaimBounty {
+            campaign_id,
+            bug_id,
+        },
+    )
+}
+
+pub fn mint_campaign_master(
+    player: Pubkey,
+    asset: Pubkey,
+    collection: Pubkey,
+    campaign_id: u8,
+) -> Instruction {
+    build(
+        accounts::MintCampaignMaster {
+            player,
+            asset,
+            collection,
+            collection_authority: find_collection_authority_address(&collection).0,
+            campaign: find_campaign_address(campaign_id).0,
+            player_progress: find_player_progress_address(campaign_id, &player).0,
+            core_program: CORE_PROGRAM_ID,
+            system_program: system_program::ID,
+        },
+        vec![],
+        instruction::MintCampaignMaster { campaign_id },
+    )
+}
+
+pub fn create_forge_recipe(
+    game_authority: Pubkey,
+    collection: Pubkey,
+    campaign_id: u8,
+    recipe_id: u8,
+    args: ForgeRecipeArgs,
+) -> Instruction {
+    build(
+        accounts::CreateForgeRecipe {
+            game_authority,
+            campaign: find_campaign_address(campaign_id).0,
+            collection,
+            collection_authority: find_collection_authority_address(&collection).0,
+            forge_recipe: find_forge_recipe_address(campaign_id, recipe_id).0,
+            system_program: system_program::ID,
+        },
+        vec![],
+        instruction::CreateForgeRecipe {
+            campaign_id,
+            recipe_id,
+            args,
+        },
+    )
+}
+
+/// `ingredients` are the assets to burn, in the order of the recipe's bug ids.
+pub fn forge_nft(
+    player: Pubkey,
+    asset: Pubkey,
+    collection: Pubkey,
+    campaign_id: u8,
+    recipe_id: u8,
+    ingredients: &[Pubkey],
+) -> Instruction {
+    build(
+        accounts::ForgeNft {
+            player,
+            asset,
+            collection,
+            collection_authority: find_collection_authority_address(&collection).0,
+            forge_recipe: find_forge_recipe_address(campaign_id, recipe_id).0,
+            core_program: CORE_PROGRAM_ID,
+            system_program: system_program::ID,
+        },
+        ingredients
+            .iter()
+            .map(|ingredient| AccountMeta::new(*ingredient, false))
+            .collect(),
+        instruction::ForgeNft {
+            campaign_id,
+            recipe_id,
+        },
+    )
+}
+
+pub fn burn_nft(creator: Pubkey, asset: Pubkey, collection: Pubkey) -> Instruction {
+    build(
+        accounts::BurnNft {
+            creator,
+            asset,
+            collection,
+            collection_authority: find_collection_authority_address(&collection).0,
+            core_program: CORE_PROGRAM_ID,
+            system_program: system_program::ID,
+        },
+        vec![],
+        instruction::BurnNft {},
+    )
+}
+
+pub fn update_nft_attributes(
+    creator: Pubkey,
+    asset: Pubkey,
+    collection: Pubkey,
+    attributes: Vec<NftAttribute>,
+) -> Instruction {
+    build(
+        accounts::UpdateNftAttributes {
+            creator,
+            asset,
+            collection,
+            collection_authority: find_collection_authority_address(&collection).0,
+            core_program: CORE_PROGRAM_ID,
+            system_program: system_program::ID,
+        },
+        vec![],
+        instruction::UpdateNftAttributes { attributes },
+    )
+}
+
+pub fn revoke_completion(
+    game_authority: Pubkey,
+    player: Pubkey,
+    campaign_id: u8,
+    bug_id: u8,
+    revoked_asset: Option<RevokedAsset>,
+) -> Instruction {
+    build(
+        accounts::RevokeCompletion {
+            game_authority,
+            player,
+            campaign: find_campaign_address(campaign_id).0,
+            campaign_completion: find_campaign_completion_address(campaign_id, &player, bug_id).0,
+            player_progress: find_player_progress_address(campaign_id, &player).0,
+            asset: revoked_asset.map(|r| r.asset),
+            collection: revoked_asset.map(|r| r.collection),
+            collection_authority: revoked_asset
+                .map(|r| find_collection_authority_address(&r.collection).0),
+            core_program: CORE_PROGRAM_ID,
+            system_program: system_program::ID,
+        },
+        vec![],
+        instruction::RevokeCompletion {
+            campaign_id,
+            bug_id,
+        },
+    )
+}
+
+pub fn get_player_progress(player: Pubkey, campaign_id: u8) -> Instruction {
+    build(
+        accounts::GetPlayerProgress {
+            player,
+            player_progress: find_player_progress_address(campaign_id, &player).0,
+        },
+        vec![],
+        instruction::GetPlayerProgress { campaign_id },
+    )
+}
+
+pub fn get_player_progress_batch(player: Pubkey, campaign_ids: Vec<u8>) -> Instruction {
+    let progress_accounts = campaign_ids
+        .iter()
+        .map(|campaign_id| {
+            AccountMeta::new_readonly(find_player_progress_address(*campaign_id, &player).0, false)
+        })
+        .collect();
+
+    build(
+        accounts::GetPlayerProgressBatch { player },
+        progress_accounts,
+        instruction::GetPlayerProgressBatch { campaign_ids },
+    )
+}
+
+pub fn has_completed_bug(player: Pubkey, campaign_id: u8, bug_id: u8) -> Instruction {
+    build(
+        accounts::HasCompletedBug {
+            player,
+            player_progress: find_player_progress_address(campaign_id, &player).0,
+        },
+        vec![],
+        instruction::HasCompletedBug {
+            campaign_id,
+            bug_id,
+        },
+    )
+}
+
+pub fn has_completed_bugs(player: Pubkey, campaign_id: u8, bug_ids: Vec<u8>) -> Instruction {
+    build(
+        accounts::HasCompletedBugs {
+            player,
+            player_progress: find_player_progress_address(campaign_id, &player).0,
+        },
+        vec![],
+        instruction::HasCompletedBugs {
+            campaign_id,
+            bug_ids,
+        },
+    )
+}
+
+pub fn check_completion(player: Pubkey, campaign_id: u8, bug_id: u8) -> Instruction {
+    build(
+        accounts::CheckCompletion {
+            player,
+            campaign_completion: find_campaign_completion_address(campaign_id, &player, bug_id).0,
+        },
+        vec![],
+        instruction::CheckCompletion {
+            campaign_id,
+            bug_id,
+        },
+    )
+}
+
+pub fn get_campaign_stats(campaign_id: u8) -> Instruction {
+    build(
+        accounts::GetCampaignStats {
+            campaign: find_campaign_address(campaign_id).0,
+        },
+        vec![],
+        instruction::GetCampaignStats { campaign_id },
+    )
+}
+
+pub fn get_daily_bug() -> Instruction {
+    build(
+        accounts::GetDailyBug {
+            clock: sysvar::clock::ID,
+        },
+        vec![],
+        instruction::GetDailyBug {},
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::Discriminator;
+
+    use super::*;
+
+    #[test]
+    fn start_campaign_lists_its_accounts_in_program_order() {
+        let player = Pubkey::new_unique();
+        let ix = start_campaign(player, None, 1, 2);
+
+        assert_eq!(ix.program_id, ID);
+        assert_eq!(
+            ix.accounts,
+            vec![
+                AccountMeta::new(player, true),
+                AccountMeta::new_readonly(ID, false),
+                AccountMeta::new(
+                    Pubkey::find_program_address(
+                        &[b"completion", &[1], player.as_ref(), &[2]],
+                        &ID
+                    )
+                    .0,
+                    false
+                ),
+                AccountMeta::new(
+                    Pubkey::find_program_address(&[b"campaign", &[1]], &ID).0,
+                    false
+                ),
+                AccountMeta::new_readonly(system_program::ID, false),
+            ]
+        );
+        assert_eq!(ix.data[..8], *instruction::StartCampaign::DISCRIMINATOR);
+        assert_eq!(ix.data[8..], [1, 2]);
+    }
+
+    #[test]
+    fn start_campaign_marks_a_sponsor_payer_as_a_writable_signer() {
+        let player = Pubkey::new_unique();
+        let sponsor = Pubkey::new_unique();
+        let ix = start_campaign(player, Some(sponsor), 1, 2);
+
+        assert_eq!(ix.accounts[1], AccountMeta::new(sponsor, true));
+    }
+
+    #[test]
+    fn claim_bounty_derives_the_claim_from_the_bounty() {
+        let player = Pubkey::new_unique();
+        let sponsor = Pubkey::new_unique();
+        let ix = claim_bounty(player, sponsor, 1, 2, 7, None);
+        let bounty = Pubkey::find_program_address(
+            &[b"bounty", &[1], &[2], sponsor.as_ref(), &7u32.to_le_bytes()],
+            &ID,
+        )
+        .0;
+
+        assert_eq!(ix.accounts[0], AccountMeta::new(player, true));
+        assert_eq!(ix.accounts[2], AccountMeta::new(bounty, false));
+        assert_eq!(
+            ix.accounts[3],
+            AccountMeta::new(
+                Pubkey::find_program_address(
+                    &[b"bounty_claim", bounty.as_ref(), player.as_ref()],
+                    &ID
+                )
+                .0,
+                false
+            )
+        );
+        assert_eq!(ix.data[..8], *instruction::ClaimBounty::DISCRIMINATOR);
+    }
+}
@@ -0,0 +1,15 @@
+//! Rust client for the cmpgn program.
+//!
+//! Seeds, account layouts and instruction data all come from the program crate, so the
+//! client cannot drift from what is deployed.
+
+pub mod accounts;
+pub mod filters;
+pub mod instructions;
+pub mod pda;
+
+pub use cmpgn::{
+    self, BOUNTY_CLAIM_SEED, BOUNTY_SEED, BUG_METADATA_SEED, CAMPAIGN_SEED, COLLECTION_SEED,
+    COMPLETION_SEED, ID, PROGRESS_SEED, RECIPE_SEED, REWARD_TOKENS_SEED, REWARD_VAULT_SEED,
+    TREASURY_SEED,
+};
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::{
+    BOUNTY_CLAIM_SEED, BOUNTY_SEED, BUG_METADATA_SEED, CAMPAIGN_SEED, COLLECTION_SEED, ID,
+    RECIPE_SEED, REWARD_TOKENS_SEED, REWARD_VAULT_SEED, TREASURY_SEED,
+};
+
+pub fn find_campaign_address(campaign_id: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CAMPAIGN_SEED, &campaign_id.to_le_bytes()], &ID)
+}
+
+pub fn find_collection_authority_address(collection: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COLLECTION_SEED, collection.as_ref()], &ID)
+}
+
+pub fn find_player_progress_address(campaign_id: u8, player: &Pubkey) -> (Pubkey, u8) {
+    cmpgn::cpi_helpers::find_player_progress_address(campaign_id, player)
+}
+
+pub fn find_campaign_completion_address(
+    campaign_id: u8,
+    player: &Pubkey,
+    bug_id: u8,
+) -> (Pubkey, u8) {
+    cmpgn::cpi_helpers::find_campaign_completion_address(campaign_id, player, bug_id)
+}
+
+pub fn find_bug_metadata_address(collection: &Pubkey, bug_id: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            BUG_METADATA_SEED,
+            collection.as_ref(),
+            &bug_id.to_le_bytes(),
+        ],
+        &ID,
+    )
+}
+
+pub fn find_forge_recipe_address(campaign_id: u8, recipe_id: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            RECIPE_SEED,
+            &campaign_id.to_le_bytes(),
+            &recipe_id.to_le_bytes(),
+        ],
+        &ID,
+    )
+}
+
+pub fn find_treasury_address(campaign_id: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TREASURY_SEED, &campaign_id.to_le_bytes()], &ID)
+}
+
+pub fn find_reward_vault_address(campaign_id: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REWARD_VAULT_SEED, &campaign_id.to_le_bytes()], &ID)
+}
+
+pub fn find_reward_tokens_address(campaign_id: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REWARD_TOKENS_SEED, &campaign_id.to_le_bytes()], &ID)
+}
+
+pub fn find_bounty_address(
+    campaign_id: u8,
+    bug_id: u8,
+    sponsor: &Pubkey,
+    nonce: u32,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            BOUNTY_SEED,
+            &campaign_id.to_le_bytes(),
+            &bug_id.to_le_bytes(),
+            sponsor.as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        &ID,
+    )
+}
+
+pub fn find_bounty_claim_address(bounty: &Pubkey, player: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BOUNTY_CLAIM_SEED, bounty.as_ref(), player.as_ref()], &ID)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn player_progress_address_uses_the_program_seeds() {
+        let player = Pubkey::new_unique();
+
+        assert_eq!(
+            find_player_progress_address(3, &player),
+            Pubkey::find_program_address(&[b"progress", &[3], player.as_ref()], &ID)
+        );
+    }
+
+    #[test]
+    fn campaign_completion_address_uses_the_program_seeds() {
+        let player = Pubkey::new_unique();
+
+        assert_eq!(
+            find_campaign_completion_address(3, &player, 5),
+            Pubkey::find_program_address(&[b"completion", &[3], player.as_ref(), &[5]], &ID)
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const CAMPAIGN_SEED: &[u8] = b"campaign";
+
+#[constant]
+pub const COLLECTION_SEED: &[u8] = b"collection";
+
+#[constant]
+pub const PROGRESS_SEED: &[u8] = b"progress";
+
+#[constant]
+pub const COMPLETION_SEED: &[u8] = b"completion";
+
+#[constant]
+pub const BUG_METADATA_SEED: &[u8] = b"bug_metadata";
+
+#[constant]
+pub const RECIPE_SEED: &[u8] = b"recipe";
+
+#[constant]
+pub const TREASURY_SEED: &[u8] = b"treasury";
+
+#[constant]
+pub const REWARD_VAULT_SEED: &[u8] = b"reward_vault";
+
+#[constant]
+pub const REWARD_TOKENS_SEED: &[u8] = b"reward_tokens";
+
+#[constant]
+pub const BOUNTY_SEED: &[u8] = b"bounty";
+
+#[constant]
+pub const BOUNTY_CLAIM_SEED: &[u8] = b"bounty_claim";
+
+#[constant]
+pub const MAX_BUG_ID: u8 = 20;
+
+#[constant]
+pub const BASE_COMPLETION_SCORE: u64 = 100;
+
+#[constant]
+pub const DAILY_SOLVE_MULTIPLIER: u64 = 2;
+
+pub const MAX_NFT_NAME_LEN: usize = 32;
+
+pub const MAX_NFT_URI_LEN: usize = 200;
+
+#[constant]
+pub const BUG_ID_PLACEHOLDER: &str = "{bug_id}";
+
+pub const MAX_COLLECTIONS_PER_CAMPAIGN: usize = 5;
+
+pub const MAX_TREASURY_RECIPIENTS: usize = 5;
+
+pub const MAX_SPONSORS_PER_CAMPAIGN: usize = 5;
+
+pub const MAX_BATCH_CAMPAIGNS: usize = 8;
+
+#[constant]
+pub const MAX_ROYALTY_BASIS_POINTS: u16 = 10_000;
+
+#[constant]
+pub const ATTRIBUTE_CREATOR: u16 = 1 << 0;
+
+#[constant]
+pub const ATTRIBUTE_MINTER: u16 = 1 << 1;
+
+#[constant]
+pub const ATTRIBUTE_RECIPIENT: u16 = 1 << 2;
+
+#[constant]
+pub const ATTRIBUTE_COLLECTION: u16 = 1 << 3;
+
+#[constant]
+pub const ATTRIBUTE_MINT_TIMESTAMP: u16 = 1 << 4;
+
+#[constant]
+pub const ATTRIBUTE_BUG_ID: u16 = 1 << 5;
+
+#[constant]
+pub const ATTRIBUTE_DAILY_SOLVE: u16 = 1 << 6;
+
+#[constant]
+pub const ATTRIBUTE_SCORE: u16 = 1 << 7;
+
+#[constant]
+pub const ATTRIBUTE_SOLVER: u16 = 1 << 8;
+
+#[constant]
+pub const ATTRIBUTE_CAMPAIGN_ID: u16 = 1 << 9;
+
+#[constant]
+pub const ATTRIBUTE_START_TIME: u16 = 1 << 10;
+
+#[constant]
+pub const ATTRIBUTE_COMPLETION_TIME: u16 = 1 << 11;
+
+#[constant]
+pub const ATTRIBUTE_SOLVE_DURATION: u16 = 1 << 12;
+
+#[constant]
+pub const ATTRIBUTE_ATTEMPTS: u16 = 1 << 13;
+
+#[constant]
+pub const ATTRIBUTE_RARITY: u16 = 1 << 14;
+
+#[constant]
+pub const ALL_ATTRIBUTES: u16 = (1 << 15) - 1;
@@ -0,0 +1,63 @@
+//! Helpers for programs that call into cmpgn through CPI.
+//!
+//! ```ignore
+//! let status = cmpgn::cpi_helpers::completion_status(
+//!     ctx.accounts.cmpgn_program.to_account_info(),
+//!     ctx.accounts.player.to_account_info(),
+//!     ctx.accounts.campaign_completion.to_account_info(),
+//!     campaign_id,
+//!     bug_id,
+//! )?;
+//! require!(status == CompletionStatus::Completed, MyError::BugNotCompleted);
+//! ```
+
+use anchor_lang::prelude::*;
+
+use crate::{cpi, CompletionStatus, COMPLETION_SEED, ID, PROGRESS_SEED};
+
+/// Derives the `PlayerProgress` PDA of `player` in the campaign `campaign_id`.
+pub fn find_player_progress_address(campaign_id: u8, player: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PROGRESS_SEED, &campaign_id.to_le_bytes(), player.as_ref()],
+        &ID,
+    )
+}
+
+/// Derives the `CampaignCompletion` PDA of `player` for `bug_id` in the campaign `campaign_id`.
+pub fn find_campaign_completion_address(
+    campaign_id: u8,
+    player: &Pubkey,
+    bug_id: u8,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            COMPLETION_SEED,
+            &campaign_id.to_le_bytes(),
+            player.as_ref(),
+            &bug_id.to_le_bytes(),
+        ],
+        &ID,
+    )
+}
+
+/// Calls `check_completion` and reads the status from the return data.
+///
+/// `campaign_completion` must be the address returned by
+/// [`find_campaign_completion_address`], even if the player never started the bug.
+pub fn completion_status<'info>(
+    cmpgn_program: AccountInfo<'info>,
+    player: AccountInfo<'info>,
+    campaign_completion: AccountInfo<'info>,
+    campaign_id: u8,
+    bug_id: u8,
+) -> Result<CompletionStatus> {
+    let cpi_context = CpiContext::new(
+        cmpgn_program,
+        cpi::accounts::CheckCompletion {
+            player,
+            campaign_completion,
+        },
+    );
+
+    Ok(cpi::check_completion(cpi_context, campaign_id, bug_id)?.get())
+}
@@ -26,4 +26,76 @@ pub enum ErrorCode {
     UnauthorizedPlayer,
     #[msg("NFT already minted")]
     NftAlreadyMinted,
+    #[msg("NFT name template is too long")]
+    NftNameTooLong,
+    #[msg("NFT URI template is too long")]
+    NftUriTooLong,
+    #[msg("The asset is invalid.")]
+    InvalidAsset,
+    #[msg("The collection does not mint soulbound NFTs.")]
+    CollectionNotSoulbound,
+    #[msg("Royalty basis points cannot exceed 10000.")]
+    InvalidRoyaltyBasisPoints,
+    #[msg("Royalty creator shares must add up to 100.")]
+    InvalidRoyaltyShares,
+    #[msg("Not every bug in the campaign has been completed.")]
+    CampaignNotFullyCompleted,
+    #[msg("Campaign master NFT already minted")]
+    MasterNftAlreadyMinted,
+    #[msg("The forge recipe is invalid.")]
+    InvalidForgeRecipe,
+    #[msg("The assets do not match the forge recipe.")]
+    InvalidForgeIngredients,
+    #[msg("The campaign cannot hold any more collections.")]
+    TooManyCollections,
+    #[msg("The completion has been revoked.")]
+    CompletionRevoked,
+    #[msg("The collection does not allow revoking NFTs.")]
+    CollectionNotRevocable,
+    #[msg("Treasury recipient shares must add up to 100.")]
+    InvalidTreasuryRecipients,
+    #[msg("The mint fee accounts are missing or invalid.")]
+    InvalidMintFeeAccounts,
+    #[msg("The treasury payout accounts are missing or invalid.")]
+    InvalidPayoutAccounts,
+    #[msg("The treasury does not hold enough funds.")]
+    InsufficientTreasuryFunds,
+    #[msg("The campaign cannot hold any more sponsors.")]
+    TooManySponsors,
+    #[msg("The payer is not a sponsor of the campaign.")]
+    UnauthorizedPayer,
+    #[msg("The attribute schema contains unknown attributes or omits the bug ID.")]
+    InvalidAttributeSchema,
+    #[msg("The rarity tier thresholds are invalid.")]
+    InvalidRarityTiers,
+    #[msg("The reward budget is not covered by the vault balance.")]
+    InvalidRewardBudget,
+    #[msg("The reward accounts are missing or invalid.")]
+    InvalidRewardAccounts,
+    #[msg("The reward for this completion has already been claimed.")]
+    RewardAlreadyClaimed,
+    #[msg("There is no reward for this bug.")]
+    NoRewardForBug,
+    #[msg("The campaign reward budget has been exhausted.")]
+    RewardBudgetExhausted,
+    #[msg("The reward vault does not hold enough tokens.")]
+    InsufficientRewardFunds,
+    #[msg("The bounty amount, solver count or expiry is invalid.")]
+    InvalidBounty,
+    #[msg("The bounty token accounts are missing or invalid.")]
+    InvalidBountyAccounts,
+    #[msg("The bounty has expired.")]
+    BountyExpired,
+    #[msg("The bounty has not expired yet.")]
+    BountyNotExpired,
+    #[msg("The completion is not eligible for the bounty.")]
+    NotEligibleForBounty,
+    #[msg("Too many entries were requested in one call.")]
+    BatchTooLarge,
+    #[msg("The player progress accounts are missing or invalid.")]
+    InvalidProgressAccounts,
+    #[msg("This attribute is set at mint time and cannot be updated.")]
+    ProtectedAttribute,
+    #[msg("Every payout of the bounty has been claimed.")]
+    BountyFullyClaimed,
 }
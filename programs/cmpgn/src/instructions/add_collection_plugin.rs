@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    instructions::AddCollectionPluginV1CpiBuilder,
+    types::{Attribute, Attributes, Plugin},
+    ID as CORE_PROGRAM_ID,
+};
+
+use crate::{
+    error::ErrorCode,
+    instructions::{create_collection::RoyaltyArgs, update_nft_attributes::NftAttribute},
+    state::CollectionAuthority,
+    COLLECTION_SEED,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub enum CollectionPluginArgs {
+    Royalties(RoyaltyArgs),
+    Attributes(Vec<NftAttribute>),
+}
+
+impl CollectionPluginArgs {
+    pub fn to_plugin(&self) -> Result<Plugin> {
+        match self {
+            CollectionPluginArgs::Royalties(royalties) => {
+                royalties.validate()?;
+                Ok(royalties.to_plugin())
+            }
+            CollectionPluginArgs::Attributes(attributes) => Ok(Plugin::Attributes(Attributes {
+                attribute_list: attributes
+                    .iter()
+                    .map(|attribute| Attribute {
+                        key: attribute.key.clone(),
+                        value: attribute.value.clone(),
+                    })
+                    .collect(),
+            })),
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct AddCollectionPlugin<'info> {
+    #[account(
+        mut,
+        constraint = collection_authority.creator == creator.key() @ ErrorCode::NotAuthorized
+    )]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = collection.owner == &CORE_PROGRAM_ID @ ErrorCode::InvalidCollection,
+        constraint = collection.key() == collection_authority.collection @ ErrorCode::InvalidCollection
+    )]
+    /// CHECK: This will also be checked by core
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [COLLECTION_SEED, collection.key().as_ref()],
+        bump = collection_authority.bump,
+    )]
+    pub collection_authority: Box<Account<'info, CollectionAuthority>>,
+
+    #[account(address = CORE_PROGRAM_ID)]
+    /// CHECK: This will also be checked by core
+    pub core_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddCollectionPlugin<'info> {
+    pub fn add_collection_plugin(&mut self, plugin: CollectionPluginArgs) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            COLLECTION_SEED,
+            &self.collection.key().to_bytes(),
+            &[self.collection_authority.bump],
+        ]];
+
+        AddCollectionPluginV1CpiBuilder::new(&self.core_program.to_account_info())
+            .collection(&self.collection.to_account_info())
+            .payer(&self.creator.to_account_info())
+            .authority(Some(&self.collection_authority.to_account_info()))
+            .system_program(&self.system_program.to_account_info())
+            .plugin(plugin.to_plugin()?)
+            .invoke_signed(signer_seeds)?;
+
+        Ok(())
+    }
+}
+
+pub(crate) fn handler(
+    ctx: Context<AddCollectionPlugin>,
+    plugin: CollectionPluginArgs,
+) -> Result<()> {
+    ctx.accounts.add_collection_plugin(plugin)?;
+
+    Ok(())
+}
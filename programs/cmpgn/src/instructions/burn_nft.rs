@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use mpl_core::{instructions::BurnV1CpiBuilder, ID as CORE_PROGRAM_ID};
+
+use crate::{error::ErrorCode, state::CollectionAuthority, COLLECTION_SEED};
+
+#[derive(Accounts)]
+pub struct BurnNft<'info> {
+    #[account(
+        mut,
+        constraint = collection_authority.creator == creator.key() @ ErrorCode::NotAuthorized
+    )]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = asset.owner == &CORE_PROGRAM_ID @ ErrorCode::InvalidAsset,
+    )]
+    /// CHECK: This will also be checked by core
+    pub asset: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = collection.owner == &CORE_PROGRAM_ID @ ErrorCode::InvalidCollection,
+        constraint = collection.key() == collection_authority.collection @ ErrorCode::InvalidCollection
+    )]
+    /// CHECK: This will also be checked by core
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [COLLECTION_SEED, collection.key().as_ref()],
+        bump = collection_authority.bump,
+        constraint = collection_authority.soulbound @ ErrorCode::CollectionNotSoulbound,
+    )]
+    pub collection_authority: Box<Account<'info, CollectionAuthority>>,
+
+    #[account(address = CORE_PROGRAM_ID)]
+    /// CHECK: This will also be checked by core
+    pub core_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> BurnNft<'info> {
+    pub fn burn_nft(&mut self) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            COLLECTION_SEED,
+            &self.collection.key().to_bytes(),
+            &[self.collection_authority.bump],
+        ]];
+
+        BurnV1CpiBuilder::new(&self.core_program.to_account_info())
+            .asset(&self.asset.to_account_info())
+            .collection(Some(&self.collection.to_account_info()))
+            .payer(&self.creator.to_account_info())
+            .authority(Some(&self.collection_authority.to_account_info()))
+            .system_program(Some(&self.system_program.to_account_info()))
+            .invoke_signed(signer_seeds)?;
+
+        Ok(())
+    }
+}
+
+pub(crate) fn handler(ctx: Context<BurnNft>) -> Result<()> {
+    ctx.accounts.burn_nft()?;
+
+    Ok(())
+}
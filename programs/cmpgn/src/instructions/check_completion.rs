@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{CampaignCompletion, COMPLETION_SEED};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CompletionStatus {
+    NotStarted,
+    InProgress,
+    Completed,
+    Revoked,
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u8, bug_id: u8)]
+pub struct CheckCompletion<'info> {
+    /// CHECK: Only used to derive the campaign completion PDA
+    pub player: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [COMPLETION_SEED, campaign_id.to_le_bytes().as_ref(), player.key().as_ref(), bug_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    /// CHECK: The completion may not exist yet, its address is checked through the seeds
+    pub campaign_completion: UncheckedAccount<'info>,
+}
+
+impl<'info> CheckCompletion<'info> {
+    pub fn check_completion(&self) -> Result<CompletionStatus> {
+        if self.campaign_completion.data_is_empty() {
+            return Ok(CompletionStatus::NotStarted);
+        }
+
+        let data = self.campaign_completion.try_borrow_data()?;
+        let campaign_completion = CampaignCompletion::try_deserialize(&mut &data[..])?;
+
+        let status = if campaign_completion.revoked {
+            CompletionStatus::Revoked
+        } else if campaign_completion.campaign_end.is_some() {
+            CompletionStatus::Completed
+        } else {
+            CompletionStatus::InProgress
+        };
+
+        Ok(status)
+    }
+}
+
+pub(crate) fn handler(
+    ctx: Context<CheckCompletion>,
+    _campaign_id: u8,
+    _bug_id: u8,
+) -> Result<CompletionStatus> {
+    ctx.accounts.check_completion()
+}
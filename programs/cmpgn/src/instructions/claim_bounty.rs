@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{
+    error::ErrorCode, Bounty, BountyClaim, CampaignCompletion, BOUNTY_CLAIM_SEED, BOUNTY_SEED,
+    COMPLETION_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u8, bug_id: u8)]
+pub struct ClaimBounty<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        seeds = [COMPLETION_SEED, campaign_id.to_le_bytes().as_ref(), player.key().as_ref(), bug_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = campaign_completion.player == player.key() @ ErrorCode::UnauthorizedPlayer,
+        constraint = campaign_completion.campaign_end.is_some() @ ErrorCode::CampaignNotCompleted,
+        constraint = !campaign_completion.revoked @ ErrorCode::CompletionRevoked,
+    )]
+    pub campaign_completion: Box<Account<'info, CampaignCompletion>>,
+
+    #[account(
+        mut,
+        seeds = [BOUNTY_SEED, campaign_id.to_le_bytes().as_ref(), bug_id.to_le_bytes().as_ref(), bounty.sponsor.as_ref(), bounty.nonce.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub bounty: Box<Account<'info, Bounty>>,
+
+    #[account(
+        init,
+        payer = player,
+        space = BountyClaim::DISCRIMINATOR.len() + BountyClaim::INIT_SPACE,
+        seeds = [BOUNTY_CLAIM_SEED, bounty.key().as_ref(), player.key().as_ref()],
+        bump,
+    )]
+    pub bounty_claim: Box<Account<'info, BountyClaim>>,
+
+    #[account(mut)]
+    pub escrow_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub player_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub payment_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimBounty<'info> {
+    pub fn claim_bounty(
+        &mut self,
+        campaign_id: u8,
+        bug_id: u8,
+        bumps: &ClaimBountyBumps,
+    ) -> Result<()> {
+        require!(
+            Clock::get()?.unix_timestamp < self.bounty.expires_at,
+            ErrorCode::BountyExpired
+        );
+        require!(
+            self.bounty
+                .is_eligible(self.campaign_completion.solver_number),
+            ErrorCode::NotEligibleForBounty
+        );
+        require!(
+            self.bounty.claimed < self.bounty.max_solvers,
+            ErrorCode::BountyFullyClaimed
+        );
+
+        let amount = self.bounty.amount_per_solver;
+
+        match self.bounty.payment_mint {
+            None => {
+                self.bounty.sub_lamports(amount)?;
+                self.player.add_lamports(amount)?;
+            }
+            Some(payment_mint_key) => {
+                let (
+                    Some(escrow_token_account),
+                    Some(player_token_account),
+                    Some(payment_mint),
+                    Some(token_program),
+                ) = (
+                    self.escrow_token_account.as_ref(),
+                    self.player_token_account.as_ref(),
+                    self.payment_mint.as_ref(),
+                    self.token_program.as_ref(),
+                )
+                else {
+                    return err!(ErrorCode::InvalidBountyAccounts);
+                };
+
+                require_keys_eq!(
+                    payment_mint.key(),
+                    payment_mint_key,
+                    ErrorCode::InvalidBountyAccounts
+                );
+                require_keys_eq!(
+                    escrow_token_account.owner,
+                    self.bounty.key(),
+                    ErrorCode::InvalidBountyAccounts
+                );
+
+                let sponsor = self.bounty.sponsor;
+                let signer_seeds: &[&[&[u8]]] = &[&[
+                    BOUNTY_SEED,
+                    &campaign_id.to_le_bytes(),
+                    &bug_id.to_le_bytes(),
+                    sponsor.as_ref(),
+                    &self.bounty.nonce.to_le_bytes(),
+                    &[self.bounty.bump],
+                ]];
+
+                transfer_checked(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        TransferChecked {
+                            from: escrow_token_account.to_account_info(),
+                            mint: payment_mint.to_account_info(),
+                            to: player_token_account.to_account_info(),
+                            authority: self.bounty.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    amount,
+                    payment_mint.decimals,
+                )?;
+            }
+        }
+
+        self.bounty.claimed += 1;
+
+        self.bounty_claim.set_inner(BountyClaim {
+            bounty: self.bounty.key(),
+            player: self.player.key(),
+            bump: bumps.bounty_claim,
+        });
+
+        Ok(())
+    }
+}
+
+pub(crate) fn handler(ctx: Context<ClaimBounty>, campaign_id: u8, bug_id: u8) -> Result<()> {
+    ctx.accounts.claim_bounty(campaign_id, bug_id, &ctx.bumps)?;
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{
+    error::ErrorCode, CampaignCompletion, RewardVault, COMPLETION_SEED, MAX_BUG_ID,
+    REWARD_TOKENS_SEED, REWARD_VAULT_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u8, bug_id: u8)]
+pub struct ClaimReward<'info> {
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [COMPLETION_SEED, campaign_id.to_le_bytes().as_ref(), player.key().as_ref(), bug_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = campaign_completion.player == player.key() @ ErrorCode::UnauthorizedPlayer,
+        constraint = campaign_completion.campaign_end.is_some() @ ErrorCode::CampaignNotCompleted,
+        constraint = !campaign_completion.revoked @ ErrorCode::CompletionRevoked,
+        constraint = !campaign_completion.reward_claimed @ ErrorCode::RewardAlreadyClaimed,
+    )]
+    pub campaign_completion: Box<Account<'info, CampaignCompletion>>,
+
+    #[account(
+        mut,
+        seeds = [REWARD_VAULT_SEED, campaign_id.to_le_bytes().as_ref()],
+        bump,
+        has_one = reward_mint @ ErrorCode::InvalidRewardAccounts,
+    )]
+    pub reward_vault: Box<Account<'info, RewardVault>>,
+
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [REWARD_TOKENS_SEED, campaign_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::token_program = token_program,
+    )]
+    pub player_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ClaimReward<'info> {
+    pub fn claim_reward(&mut self, campaign_id: u8, bug_id: u8) -> Result<()> {
+        require!((1..=MAX_BUG_ID).contains(&bug_id), ErrorCode::InvalidBugId);
+
+        let amount = self.reward_vault.amounts[(bug_id - 1) as usize];
+        require!(amount > 0, ErrorCode::NoRewardForBug);
+
+        let total_claimed = self
+            .reward_vault
+            .total_claimed
+            .checked_add(amount)
+            .ok_or(ErrorCode::RewardBudgetExhausted)?;
+        require!(
+            total_claimed <= self.reward_vault.total_budget,
+            ErrorCode::RewardBudgetExhausted
+        );
+        require!(
+            amount <= self.vault_token_account.amount,
+            ErrorCode::InsufficientRewardFunds
+        );
+
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            REWARD_VAULT_SEED,
+            &campaign_id.to_le_bytes(),
+            &[self.reward_vault.bump],
+        ]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.vault_token_account.to_account_info(),
+                    mint: self.reward_mint.to_account_info(),
+                    to: self.player_token_account.to_account_info(),
+                    authority: self.reward_vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            self.reward_mint.decimals,
+        )?;
+
+        self.reward_vault.total_claimed = total_claimed;
+        self.campaign_completion.reward_claimed = true;
+
+        Ok(())
+    }
+}
+
+pub(crate) fn handler(ctx: Context<ClaimReward>, campaign_id: u8, bug_id: u8) -> Result<()> {
+    ctx.accounts.claim_reward(campaign_id, bug_id)?;
+
+    Ok(())
+}
@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use mpl_core::ID as CORE_PROGRAM_ID;
+
+use crate::{
+    error::ErrorCode,
+    instructions::{
+        mint_nft::{CompletionNftMint, MintFeePayment},
+        record_campaign_completion::record_completion,
+    },
+    state::CollectionAuthority,
+    Campaign, CampaignCompletion, PlayerProgress, Treasury, BUG_METADATA_SEED, CAMPAIGN_SEED,
+    COLLECTION_SEED, COMPLETION_SEED, PROGRESS_SEED, TREASURY_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u8, bug_id: u8)]
+pub struct CompleteAndMint<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    #[account(
+        mut,
+        constraint = asset.data_is_empty() @ ErrorCode::AssetAlreadyInitialized
+    )]
+    pub asset: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = collection.owner == &CORE_PROGRAM_ID @ ErrorCode::InvalidCollection,
+        constraint = !collection.data_is_empty() @ ErrorCode::CollectionNotInitialized,
+        constraint = collection.key() == collection_authority.collection @ ErrorCode::InvalidCollection
+    )]
+    /// CHECK: This will also be checked by core
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [COLLECTION_SEED, collection.key().as_ref()],
+        bump,
+        constraint = collection_authority.campaign_id == campaign_id @ ErrorCode::InvalidCollection,
+    )]
+    pub collection_authority: Box<Account<'info, CollectionAuthority>>,
+
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, campaign_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = payer.as_ref().is_none_or(|payer| payer.key() == player.key() || campaign.sponsors.contains(&payer.key())) @ ErrorCode::UnauthorizedPayer,
+        constraint = campaign.collections.contains(&collection.key()) @ ErrorCode::InvalidCollection,
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    #[account(
+        seeds = [BUG_METADATA_SEED, collection.key().as_ref(), bug_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    /// CHECK: Empty until the authority sets metadata for the bug, decoded when minting
+    pub bug_metadata: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [COMPLETION_SEED, campaign_id.to_le_bytes().as_ref(), player.key().as_ref(), bug_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub campaign_completion: Box<Account<'info, CampaignCompletion>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer.as_ref().map_or_else(|| player.to_account_info(), |payer| payer.to_account_info()),
+        space = PlayerProgress::DISCRIMINATOR.len() + PlayerProgress::INIT_SPACE,
+        seeds = [PROGRESS_SEED, campaign_id.to_le_bytes().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    pub player_progress: Box<Account<'info, PlayerProgress>>,
+
+    /// CHECK: Any wallet chosen by the player to own the NFT
+    pub recipient: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, campaign_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub treasury: Option<Box<Account<'info, Treasury>>>,
+
+    #[account(mut)]
+    pub player_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub treasury_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub payment_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    #[account(address = CORE_PROGRAM_ID)]
+    /// CHECK: This will also be checked by core
+    pub core_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CompleteAndMint<'info> {
+    pub fn complete_and_mint(
+        &mut self,
+        campaign_id: u8,
+        bug_id: u8,
+        bumps: &CompleteAndMintBumps,
+    ) -> Result<()> {
+        record_completion(
+            self.player.key(),
+            campaign_id,
+            bug_id,
+            &mut self.campaign,
+            &mut self.campaign_completion,
+            &mut self.player_progress,
+            bumps.player_progress,
+        )?;
+
+        MintFeePayment {
+            campaign: &self.campaign,
+            player: self.player.to_account_info(),
+            treasury: self.treasury.as_deref(),
+            player_token_account: self.player_token_account.as_deref(),
+            treasury_token_account: self.treasury_token_account.as_deref(),
+            payment_mint: self.payment_mint.as_deref(),
+            token_program: self.token_program.as_ref(),
+            system_program: self.system_program.to_account_info(),
+        }
+        .pay()?;
+
+        CompletionNftMint {
+            campaign: &self.campaign,
+            core_program: self.core_program.to_account_info(),
+            asset: self.asset.to_account_info(),
+            collection: self.collection.to_account_info(),
+            collection_authority: &self.collection_authority,
+            bug_metadata: self.bug_metadata.to_account_info(),
+            player: self.player.to_account_info(),
+            recipient: self.recipient.as_ref().map_or_else(
+                || self.player.to_account_info(),
+                |recipient| recipient.to_account_info(),
+            ),
+            payer: self.payer.as_ref().map_or_else(
+                || self.player.to_account_info(),
+                |payer| payer.to_account_info(),
+            ),
+            system_program: self.system_program.to_account_info(),
+        }
+        .mint(bug_id, &self.campaign_completion)?;
+
+        self.campaign_completion.nft_mint_address = Some(self.asset.key());
+        Ok(())
+    }
+}
+
+pub(crate) fn handler(ctx: Context<CompleteAndMint>, campaign_id: u8, bug_id: u8) -> Result<()> {
+    ctx.accounts
+        .complete_and_mint(campaign_id, bug_id, &ctx.bumps)?;
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{
+    error::ErrorCode, Campaign, RewardVault, CAMPAIGN_SEED, MAX_BUG_ID, REWARD_TOKENS_SEED,
+    REWARD_VAULT_SEED,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ConfigureRewardsArgs {
+    pub amounts: [u64; MAX_BUG_ID as usize],
+    pub total_budget: u64,
+    pub deposit: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u8)]
+pub struct ConfigureRewards<'info> {
+    #[account(mut)]
+    pub game_authority: Signer<'info>,
+
+    #[account(
+        seeds = [CAMPAIGN_SEED, campaign_id.to_le_bytes().as_ref()],
+        bump,
+        has_one = game_authority
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = game_authority,
+        space = RewardVault::DISCRIMINATOR.len() + RewardVault::INIT_SPACE,
+        seeds = [REWARD_VAULT_SEED, campaign_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub reward_vault: Box<Account<'info, RewardVault>>,
+
+    #[account(
+        init_if_needed,
+        payer = game_authority,
+        seeds = [REWARD_TOKENS_SEED, campaign_id.to_le_bytes().as_ref()],
+        bump,
+        token::mint = reward_mint,
+        token::authority = reward_vault,
+        token::token_program = token_program,
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ConfigureRewards<'info> {
+    pub fn configure_rewards(
+        &mut self,
+        campaign_id: u8,
+        args: ConfigureRewardsArgs,
+        bumps: &ConfigureRewardsBumps,
+    ) -> Result<()> {
+        let total_claimed = self.reward_vault.total_claimed;
+
+        require!(
+            args.total_budget >= total_claimed,
+            ErrorCode::InvalidRewardBudget
+        );
+
+        if args.deposit > 0 {
+            let authority_token_account = self
+                .authority_token_account
+                .as_ref()
+                .ok_or(ErrorCode::InvalidRewardAccounts)?;
+
+            transfer_checked(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    TransferChecked {
+                        from: authority_token_account.to_account_info(),
+                        mint: self.reward_mint.to_account_info(),
+                        to: self.vault_token_account.to_account_info(),
+                        authority: self.game_authority.to_account_info(),
+                    },
+                ),
+                args.deposit,
+                self.reward_mint.decimals,
+            )?;
+
+            self.vault_token_account.reload()?;
+        }
+
+        // Every unclaimed token in the budget must already sit in the vault.
+        require!(
+            args.total_budget - total_claimed <= self.vault_token_account.amount,
+            ErrorCode::InvalidRewardBudget
+        );
+
+        self.reward_vault.set_inner(RewardVault {
+            campaign_id,
+            reward_mint: self.reward_mint.key(),
+            amounts: args.amounts,
+            total_budget: args.total_budget,
+            total_claimed,
+            bump: bumps.reward_vault,
+        });
+
+        Ok(())
+    }
+}
+
+pub(crate) fn handler(
+    ctx: Context<ConfigureRewards>,
+    campaign_id: u8,
+    args: ConfigureRewardsArgs,
+) -> Result<()> {
+    ctx.accounts
+        .configure_rewards(campaign_id, args, &ctx.bumps)?;
+
+    Ok(())
+}
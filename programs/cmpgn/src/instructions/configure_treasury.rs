@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ErrorCode, Campaign, Treasury, TreasuryRecipient, CAMPAIGN_SEED,
+    MAX_TREASURY_RECIPIENTS, TREASURY_SEED,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ConfigureTreasuryArgs {
+    pub mint_price: u64,
+    pub payment_mint: Option<Pubkey>,
+    pub recipients: Vec<TreasuryRecipient>,
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u8)]
+pub struct ConfigureTreasury<'info> {
+    #[account(mut)]
+    pub game_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, campaign_id.to_le_bytes().as_ref()],
+        bump,
+        has_one = game_authority
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    #[account(
+        init_if_needed,
+        payer = game_authority,
+        space = Treasury::DISCRIMINATOR.len() + Treasury::INIT_SPACE,
+        seeds = [TREASURY_SEED, campaign_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ConfigureTreasury<'info> {
+    pub fn configure_treasury(
+        &mut self,
+        campaign_id: u8,
+        args: ConfigureTreasuryArgs,
+        bumps: &ConfigureTreasuryBumps,
+    ) -> Result<()> {
+        require!(
+            !args.recipients.is_empty() && args.recipients.len() <= MAX_TREASURY_RECIPIENTS,
+            ErrorCode::InvalidTreasuryRecipients
+        );
+
+        let total_percentage: u16 = args
+            .recipients
+            .iter()
+            .map(|recipient| recipient.percentage as u16)
+            .sum();
+        require!(
+            total_percentage == 100,
+            ErrorCode::InvalidTreasuryRecipients
+        );
+
+        self.campaign.mint_price = args.mint_price;
+        self.campaign.payment_mint = args.payment_mint;
+
+        self.treasury.set_inner(Treasury {
+            campaign_id,
+            recipients: args.recipients,
+            bump: bumps.treasury,
+        });
+
+        Ok(())
+    }
+}
+
+pub(crate) fn handler(
+    ctx: Context<ConfigureTreasury>,
+    campaign_id: u8,
+    args: ConfigureTreasuryArgs,
+) -> Result<()> {
+    ctx.accounts
+        .configure_treasury(campaign_id, args, &ctx.bumps)?;
+
+    Ok(())
+}
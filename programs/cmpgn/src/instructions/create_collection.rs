@@ -1,7 +1,78 @@
 use anchor_lang::prelude::*;
-use mpl_core::{instructions::CreateCollectionV2CpiBuilder, ID as CORE_PROGRAM_ID};
+use mpl_core::{
+    instructions::CreateCollectionV2CpiBuilder,
+    types::{
+        Creator, PermanentBurnDelegate, Plugin, PluginAuthority, PluginAuthorityPair, Royalties,
+        RuleSet,
+    },
+    ID as CORE_PROGRAM_ID,
+};
 
-use crate::{error::ErrorCode, state::CollectionAuthority, Campaign};
+use crate::{
+    error::ErrorCode, state::CollectionAuthority, Campaign, CAMPAIGN_SEED, COLLECTION_SEED,
+    MAX_COLLECTIONS_PER_CAMPAIGN, MAX_NFT_NAME_LEN, MAX_NFT_URI_LEN, MAX_ROYALTY_BASIS_POINTS,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RoyaltyCreator {
+    pub address: Pubkey,
+    pub percentage: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum RoyaltyRuleSet {
+    None,
+    ProgramAllowList(Vec<Pubkey>),
+    ProgramDenyList(Vec<Pubkey>),
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RoyaltyArgs {
+    pub basis_points: u16,
+    pub creators: Vec<RoyaltyCreator>,
+    pub rule_set: RoyaltyRuleSet,
+}
+
+impl RoyaltyArgs {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.basis_points <= MAX_ROYALTY_BASIS_POINTS,
+            ErrorCode::InvalidRoyaltyBasisPoints
+        );
+
+        let total_percentage: u16 = self
+            .creators
+            .iter()
+            .map(|creator| creator.percentage as u16)
+            .sum();
+        require!(total_percentage == 100, ErrorCode::InvalidRoyaltyShares);
+
+        Ok(())
+    }
+
+    pub fn to_plugin(&self) -> Plugin {
+        let rule_set = match &self.rule_set {
+            RoyaltyRuleSet::None => RuleSet::None,
+            RoyaltyRuleSet::ProgramAllowList(programs) => {
+                RuleSet::ProgramAllowList(programs.clone())
+            }
+            RoyaltyRuleSet::ProgramDenyList(programs) => RuleSet::ProgramDenyList(programs.clone()),
+        };
+
+        Plugin::Royalties(Royalties {
+            basis_points: self.basis_points,
+            creators: self
+                .creators
+                .iter()
+                .map(|creator| Creator {
+                    address: creator.address,
+                    percentage: creator.percentage,
+                })
+                .collect(),
+            rule_set,
+        })
+    }
+}
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CreateCollectionArgs {
@@ -9,6 +80,11 @@ pub struct CreateCollectionArgs {
     pub uri: String,
     pub nft_name: String,
     pub nft_uri: String,
+    pub master_nft_name: String,
+    pub master_nft_uri: String,
+    pub soulbound: bool,
+    pub revocable: bool,
+    pub royalties: Option<RoyaltyArgs>,
 }
 
 #[derive(Accounts)]
@@ -30,14 +106,14 @@ pub struct CreateCollection<'info> {
         init,
         payer = creator,
         space = CollectionAuthority::DISCRIMINATOR.len() + CollectionAuthority::INIT_SPACE,
-        seeds = [b"collection", collection.key().as_ref()],
+        seeds = [COLLECTION_SEED, collection.key().as_ref()],
         bump,
     )]
     pub collection_authority: Account<'info, CollectionAuthority>,
 
     #[account(
         mut,
-        seeds = [b"campaign", campaign_id.to_le_bytes().as_ref()],
+        seeds = [CAMPAIGN_SEED, campaign_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub campaign: Account<'info, Campaign>,
@@ -55,16 +131,52 @@ impl<'info> CreateCollection<'info> {
         args: CreateCollectionArgs,
         bumps: &CreateCollectionBumps,
     ) -> Result<()> {
+        require!(
+            self.campaign.collections.len() < MAX_COLLECTIONS_PER_CAMPAIGN,
+            ErrorCode::TooManyCollections
+        );
+        require!(
+            args.nft_name.len() <= MAX_NFT_NAME_LEN
+                && args.master_nft_name.len() <= MAX_NFT_NAME_LEN,
+            ErrorCode::NftNameTooLong
+        );
+        require!(
+            args.nft_uri.len() <= MAX_NFT_URI_LEN && args.master_nft_uri.len() <= MAX_NFT_URI_LEN,
+            ErrorCode::NftUriTooLong
+        );
+
+        let mut plugins = vec![];
+        if let Some(royalties) = &args.royalties {
+            royalties.validate()?;
+            plugins.push(PluginAuthorityPair {
+                plugin: royalties.to_plugin(),
+                authority: None,
+            });
+        }
+        if args.revocable {
+            plugins.push(PluginAuthorityPair {
+                plugin: Plugin::PermanentBurnDelegate(PermanentBurnDelegate {}),
+                authority: Some(PluginAuthority::UpdateAuthority),
+            });
+        }
+
         self.collection_authority.set_inner(CollectionAuthority {
             creator: self.creator.key(),
             collection: self.collection.key(),
+            campaign_id,
             nft_name: args.nft_name,
             nft_uri: args.nft_uri,
+            master_nft_name: args.master_nft_name,
+            master_nft_uri: args.master_nft_uri,
+            soulbound: args.soulbound,
+            revocable: args.revocable,
             bump: bumps.collection_authority,
         });
 
+        self.campaign.collections.push(self.collection.key());
+
         let signer_seeds: &[&[&[u8]]] = &[&[
-            b"collection",
+            COLLECTION_SEED,
             &self.collection.key().to_bytes(),
             &[bumps.collection_authority],
         ]];
@@ -76,7 +188,7 @@ impl<'info> CreateCollection<'info> {
             .system_program(&self.system_program.to_account_info())
             .name(args.name)
             .uri(args.uri)
-            .plugins(vec![])
+            .plugins(plugins)
             .external_plugin_adapters(vec![])
             .invoke_signed(signer_seeds)?;
 
@@ -84,7 +196,7 @@ impl<'info> CreateCollection<'info> {
     }
 }
 
-pub fn handler(
+pub(crate) fn handler(
     ctx: Context<CreateCollection>,
     campaign_id: u8,
     args: CreateCollectionArgs,
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ErrorCode, Campaign, CollectionAuthority, ForgeRecipe, CAMPAIGN_SEED, COLLECTION_SEED,
+    MAX_BUG_ID, MAX_NFT_NAME_LEN, MAX_NFT_URI_LEN, RECIPE_SEED,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ForgeRecipeArgs {
+    pub bug_ids: Vec<u8>,
+    pub reward_name: String,
+    pub reward_uri: String,
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u8, recipe_id: u8)]
+pub struct CreateForgeRecipe<'info> {
+    #[account(mut)]
+    pub game_authority: Signer<'info>,
+
+    #[account(
+        seeds = [CAMPAIGN_SEED, campaign_id.to_le_bytes().as_ref()],
+        bump,
+        has_one = game_authority
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    /// CHECK: Only used to derive the collection authority PDA
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [COLLECTION_SEED, collection.key().as_ref()],
+        bump,
+        constraint = collection_authority.campaign_id == campaign_id @ ErrorCode::InvalidCollection,
+        constraint = campaign.collections.contains(&collection.key()) @ ErrorCode::InvalidCollection,
+    )]
+    pub collection_authority: Box<Account<'info, CollectionAuthority>>,
+
+    #[account(
+        init,
+        payer = game_authority,
+        space = ForgeRecipe::DISCRIMINATOR.len() + ForgeRecipe::INIT_SPACE,
+        seeds = [RECIPE_SEED, campaign_id.to_le_bytes().as_ref(), recipe_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub forge_recipe: Box<Account<'info, ForgeRecipe>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateForgeRecipe<'info> {
+    pub fn create_forge_recipe(
+        &mut self,
+        campaign_id: u8,
+        recipe_id: u8,
+        args: ForgeRecipeArgs,
+        bumps: &CreateForgeRecipeBumps,
+    ) -> Result<()> {
+        require!(!args.bug_ids.is_empty(), ErrorCode::InvalidForgeRecipe);
+        require!(
+            args.bug_ids
+                .iter()
+                .all(|bug_id| (1..=MAX_BUG_ID).contains(bug_id)),
+            ErrorCode::InvalidBugId
+        );
+
+        let mut unique_bug_ids = args.bug_ids.clone();
+        unique_bug_ids.sort_unstable();
+        unique_bug_ids.dedup();
+        require!(
+            unique_bug_ids.len() == args.bug_ids.len(),
+            ErrorCode::InvalidForgeRecipe
+        );
+
+        require!(
+            args.reward_name.len() <= MAX_NFT_NAME_LEN,
+            ErrorCode::NftNameTooLong
+        );
+        require!(
+            args.reward_uri.len() <= MAX_NFT_URI_LEN,
+            ErrorCode::NftUriTooLong
+        );
+
+        self.forge_recipe.set_inner(ForgeRecipe {
+            campaign_id,
+            recipe_id,
+            collection: self.collection.key(),
+            bug_ids: args.bug_ids,
+            reward_name: args.reward_name,
+            reward_uri: args.reward_uri,
+            bump: bumps.forge_recipe,
+        });
+
+        Ok(())
+    }
+}
+
+pub(crate) fn handler(
+    ctx: Context<CreateForgeRecipe>,
+    campaign_id: u8,
+    recipe_id: u8,
+    args: ForgeRecipeArgs,
+) -> Result<()> {
+    ctx.accounts
+        .create_forge_recipe(campaign_id, recipe_id, args, &ctx.bumps)?;
+
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    accounts::BaseAssetV1,
+    fetch_plugin,
+    instructions::{BurnV1CpiBuilder, CreateV2CpiBuilder},
+    types::{Attribute, Attributes, Plugin, PluginAuthorityPair, PluginType, UpdateAuthority},
+    ID as CORE_PROGRAM_ID,
+};
+
+use crate::{
+    error::ErrorCode, instructions::mint_nft::soulbound_plugins, state::CollectionAuthority,
+    ForgeRecipe, COLLECTION_SEED, RECIPE_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u8, recipe_id: u8)]
+pub struct ForgeNft<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = asset.data_is_empty() @ ErrorCode::AssetAlreadyInitialized
+    )]
+    pub asset: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = collection.owner == &CORE_PROGRAM_ID @ ErrorCode::InvalidCollection,
+        constraint = !collection.data_is_empty() @ ErrorCode::CollectionNotInitialized,
+        constraint = collection.key() == collection_authority.collection @ ErrorCode::InvalidCollection,
+        constraint = collection.key() == forge_recipe.collection @ ErrorCode::InvalidCollection
+    )]
+    /// CHECK: This will also be checked by core
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [COLLECTION_SEED, collection.key().as_ref()],
+        bump,
+    )]
+    pub collection_authority: Box<Account<'info, CollectionAuthority>>,
+
+    #[account(
+        seeds = [RECIPE_SEED, campaign_id.to_le_bytes().as_ref(), recipe_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub forge_recipe: Box<Account<'info, ForgeRecipe>>,
+
+    #[account(address = CORE_PROGRAM_ID)]
+    /// CHECK: This will also be checked by core
+    pub core_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ForgeNft<'info> {
+    pub fn forge_nft(
+        &mut self,
+        campaign_id: u8,
+        recipe_id: u8,
+        ingredients: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(
+            ingredients.len() == self.forge_recipe.bug_ids.len(),
+            ErrorCode::InvalidForgeIngredients
+        );
+
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            COLLECTION_SEED,
+            &self.collection.key().to_bytes(),
+            &[self.collection_authority.bump],
+        ]];
+
+        for (ingredient, bug_id) in ingredients.iter().zip(self.forge_recipe.bug_ids.iter()) {
+            self.verify_ingredient(ingredient, *bug_id)?;
+
+            // Soulbound assets are frozen, so only the collection's burn delegate can burn them.
+            let authority = if self.collection_authority.soulbound {
+                self.collection_authority.to_account_info()
+            } else {
+                self.player.to_account_info()
+            };
+
+            BurnV1CpiBuilder::new(&self.core_program.to_account_info())
+                .asset(ingredient)
+                .collection(Some(&self.collection.to_account_info()))
+                .payer(&self.player.to_account_info())
+                .authority(Some(&authority))
+                .system_program(Some(&self.system_program.to_account_info()))
+                .invoke_signed(signer_seeds)?;
+        }
+
+        let current_timestamp = Clock::get()?.unix_timestamp;
+
+        let mut plugins = vec![PluginAuthorityPair {
+            plugin: Plugin::Attributes(Attributes {
+                attribute_list: vec![
+                    Attribute {
+                        key: "Type".to_string(),
+                        value: "Forged".to_string(),
+                    },
+                    Attribute {
+                        key: "Creator".to_string(),
+                        value: self.collection_authority.creator.to_string(),
+                    },
+                    Attribute {
+                        key: "Minter".to_string(),
+                        value: self.player.key().to_string(),
+                    },
+                    Attribute {
+                        key: "Collection".to_string(),
+                        value: self.collection.key().to_string(),
+                    },
+                    Attribute {
+                        key: "Mint Timestamp".to_string(),
+                        value: current_timestamp.to_string(),
+                    },
+                    Attribute {
+                        key: "Campaign ID".to_string(),
+                        value: campaign_id.to_string(),
+                    },
+                    Attribute {
+                        key: "Recipe ID".to_string(),
+                        value: recipe_id.to_string(),
+                    },
+                ],
+            }),
+            authority: None,
+        }];
+
+        if self.collection_authority.soulbound {
+            plugins.extend(soulbound_plugins());
+        }
+
+        CreateV2CpiBuilder::new(&self.core_program.to_account_info())
+            .asset(&self.asset.to_account_info())
+            .collection(Some(&self.collection.to_account_info()))
+            .authority(Some(&self.collection_authority.to_account_info()))
+            .payer(&self.player.to_account_info())
+            .owner(Some(&self.player.to_account_info()))
+            .update_authority(None)
+            .system_program(&self.system_program.to_account_info())
+            .name(self.forge_recipe.reward_name.clone())
+            .uri(self.forge_recipe.reward_uri.clone())
+            .plugins(plugins)
+            .external_plugin_adapters(vec![])
+            .invoke_signed(signer_seeds)?;
+
+        Ok(())
+    }
+
+    fn verify_ingredient(&self, ingredient: &AccountInfo<'info>, bug_id: u8) -> Result<()> {
+        require!(
+            ingredient.owner == &CORE_PROGRAM_ID && ingredient.is_writable,
+            ErrorCode::InvalidAsset
+        );
+
+        let base_asset = BaseAssetV1::try_from(ingredient).map_err(|_| ErrorCode::InvalidAsset)?;
+        require!(
+            base_asset.owner == self.player.key(),
+            ErrorCode::UnauthorizedPlayer
+        );
+        require!(
+            base_asset.update_authority == UpdateAuthority::Collection(self.collection.key()),
+            ErrorCode::InvalidCollection
+        );
+
+        let (_, attributes, _) =
+            fetch_plugin::<BaseAssetV1, Attributes>(ingredient, PluginType::Attributes)
+                .map_err(|_| ErrorCode::InvalidAsset)?;
+        let expected_bug_id = bug_id.to_string();
+        require!(
+            attributes
+                .attribute_list
+                .iter()
+                .any(|attribute| attribute.key == "Bug ID" && attribute.value == expected_bug_id),
+            ErrorCode::InvalidForgeIngredients
+        );
+
+        Ok(())
+    }
+}
+
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ForgeNft<'info>>,
+    campaign_id: u8,
+    recipe_id: u8,
+) -> Result<()> {
+    ctx.accounts
+        .forge_nft(campaign_id, recipe_id, ctx.remaining_accounts)?;
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{error::ErrorCode, Bounty, Campaign, BOUNTY_SEED, CAMPAIGN_SEED, MAX_BUG_ID};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FundBountyArgs {
+    /// Lets a sponsor fund the same bug again once an earlier bounty is reclaimed.
+    pub nonce: u32,
+    pub amount_per_solver: u64,
+    pub max_solvers: u32,
+    pub expires_at: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u8, bug_id: u8, args: FundBountyArgs)]
+pub struct FundBounty<'info> {
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    #[account(
+        seeds = [CAMPAIGN_SEED, campaign_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    #[account(
+        init,
+        payer = sponsor,
+        space = Bounty::DISCRIMINATOR.len() + Bounty::INIT_SPACE,
+        seeds = [BOUNTY_SEED, campaign_id.to_le_bytes().as_ref(), bug_id.to_le_bytes().as_ref(), sponsor.key().as_ref(), args.nonce.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub bounty: Box<Account<'info, Bounty>>,
+
+    #[account(mut)]
+    pub sponsor_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub escrow_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub payment_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FundBounty<'info> {
+    pub fn fund_bounty(
+        &mut self,
+        campaign_id: u8,
+        bug_id: u8,
+        args: FundBountyArgs,
+        bumps: &FundBountyBumps,
+    ) -> Result<()> {
+        require!((1..=MAX_BUG_ID).contains(&bug_id), ErrorCode::InvalidBugId);
+        require!(
+            args.amount_per_solver > 0 && args.max_solvers > 0,
+            ErrorCode::InvalidBounty
+        );
+        require!(
+            args.expires_at > Clock::get()?.unix_timestamp,
+            ErrorCode::InvalidBounty
+        );
+
+        let total = args
+            .amount_per_solver
+            .checked_mul(args.max_solvers as u64)
+            .ok_or(ErrorCode::InvalidBounty)?;
+
+        match self.payment_mint.as_ref() {
+            None => transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.sponsor.to_account_info(),
+                        to: self.bounty.to_account_info(),
+                    },
+                ),
+                total,
+            )?,
+            Some(payment_mint) => {
+                let (Some(sponsor_token_account), Some(escrow_token_account), Some(token_program)) = (
+                    self.sponsor_token_account.as_ref(),
+                    self.escrow_token_account.as_ref(),
+                    self.token_program.as_ref(),
+                ) else {
+                    return err!(ErrorCode::InvalidBountyAccounts);
+                };
+
+                require_keys_eq!(
+                    escrow_token_account.owner,
+                    self.bounty.key(),
+                    ErrorCode::InvalidBountyAccounts
+                );
+                require_keys_eq!(
+                    escrow_token_account.mint,
+                    payment_mint.key(),
+                    ErrorCode::InvalidBountyAccounts
+                );
+
+                transfer_checked(
+                    CpiContext::new(
+                        token_program.to_account_info(),
+                        TransferChecked {
+                            from: sponsor_token_account.to_account_info(),
+                            mint: payment_mint.to_account_info(),
+                            to: escrow_token_account.to_account_info(),
+                            authority: self.sponsor.to_account_info(),
+                        },
+                    ),
+                    total,
+                    payment_mint.decimals,
+                )?;
+            }
+        }
+
+        self.bounty.set_inner(Bounty {
+            sponsor: self.sponsor.key(),
+            campaign_id,
+            bug_id,
+            nonce: args.nonce,
+            payment_mint: self
+                .payment_mint
+                .as_ref()
+                .map(|payment_mint| payment_mint.key()),
+            amount_per_solver: args.amount_per_solver,
+            // Only players who solve the bug after the bounty is funded can claim it.
+            first_solver: self.campaign.bug_solvers[(bug_id - 1) as usize] + 1,
+            max_solvers: args.max_solvers,
+            claimed: 0,
+            expires_at: args.expires_at,
+            bump: bumps.bounty,
+        });
+
+        Ok(())
+    }
+}
+
+pub(crate) fn handler(
+    ctx: Context<FundBounty>,
+    campaign_id: u8,
+    bug_id: u8,
+    args: FundBountyArgs,
+) -> Result<()> {
+    ctx.accounts
+        .fund_bounty(campaign_id, bug_id, args, &ctx.bumps)?;
+
+    Ok(())
+}
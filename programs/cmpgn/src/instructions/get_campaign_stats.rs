@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::Campaign;
+use crate::{Campaign, CAMPAIGN_SEED, MAX_BUG_ID};
 
 #[event]
 pub struct CampaignStatsEvent {
@@ -8,30 +8,41 @@ pub struct CampaignStatsEvent {
     pub total_completions: u8,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CampaignStatsView {
+    pub campaign_id: u8,
+    pub total_completions: u8,
+    pub bug_solvers: [u32; MAX_BUG_ID as usize],
+}
+
 #[derive(Accounts)]
 #[instruction(campaign_id: u8)]
 pub struct GetCampaignStats<'info> {
-    pub game_authority: Signer<'info>,
-
     #[account(
-            seeds = [b"campaign", campaign_id.to_le_bytes().as_ref()],
+            seeds = [CAMPAIGN_SEED, campaign_id.to_le_bytes().as_ref()],
             bump,
-            has_one = game_authority
         )]
     pub campaign: Account<'info, Campaign>,
 }
 
 impl<'info> GetCampaignStats<'info> {
-    pub fn get_campaign_stats(&mut self, campaign_id: u8) -> Result<()> {
+    pub fn get_campaign_stats(&self, campaign_id: u8) -> Result<CampaignStatsView> {
         emit!(CampaignStatsEvent {
             campaign_id,
             total_completions: self.campaign.total_completions
         });
 
-        Ok(())
+        Ok(CampaignStatsView {
+            campaign_id,
+            total_completions: self.campaign.total_completions,
+            bug_solvers: self.campaign.bug_solvers,
+        })
     }
 }
 
-pub fn handler(ctx: Context<GetCampaignStats>, campaign_id: u8) -> Result<()> {
+pub(crate) fn handler(
+    ctx: Context<GetCampaignStats>,
+    campaign_id: u8,
+) -> Result<CampaignStatsView> {
     ctx.accounts.get_campaign_stats(campaign_id)
 }
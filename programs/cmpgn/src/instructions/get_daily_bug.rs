@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::DailyBug;
+
 // use orao_solana_vrf::program::OraoVrf;
 // use orao_solana_vrf::state::NetworkState;
 // use orao_solana_vrf::CONFIG_ACCOUNT_SEED;
@@ -13,20 +15,18 @@ pub struct DailyBugEvent {
 
 #[derive(Accounts)]
 pub struct GetDailyBug<'info> {
-    pub player: Signer<'info>,
+    pub clock: Sysvar<'info, Clock>,
 }
 
 impl<'info> GetDailyBug<'info> {
-    pub fn get_daily_bug(&mut self) -> Result<()> {
-        let clock = Clock::get()?;
-        let day = clock.unix_timestamp / 86400;
-        let bug_id = ((day % 20_i64) + 1) as u8;
+    pub fn get_daily_bug(&self) -> Result<u8> {
+        let bug_id = DailyBug::bug_id_at(self.clock.unix_timestamp);
         emit!(DailyBugEvent { bug_id });
 
-        Ok(())
+        Ok(bug_id)
     }
 }
 
-pub fn handler(ctx: Context<GetDailyBug>) -> Result<()> {
+pub(crate) fn handler(ctx: Context<GetDailyBug>) -> Result<u8> {
     ctx.accounts.get_daily_bug()
 }
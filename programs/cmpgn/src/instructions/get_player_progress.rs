@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::PlayerProgress;
+use crate::{PlayerProgress, PROGRESS_SEED};
 
 #[event]
 pub struct PlayerProgressEvent {
@@ -10,33 +10,74 @@ pub struct PlayerProgressEvent {
     pub total_completed_bugs: u8,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PlayerProgressView {
+    pub player: Pubkey,
+    pub campaign_id: u8,
+    pub completed_bugs: Vec<u8>,
+    pub total_completed_bugs: u8,
+    pub total_score: u64,
+    pub master_mint_address: Option<Pubkey>,
+}
+
+impl From<&PlayerProgress> for PlayerProgressView {
+    fn from(player_progress: &PlayerProgress) -> Self {
+        Self {
+            player: player_progress.player,
+            campaign_id: player_progress.campaign_id,
+            completed_bugs: player_progress.completed_bugs.clone(),
+            total_completed_bugs: player_progress.total_completed_bugs,
+            total_score: player_progress.total_score,
+            master_mint_address: player_progress.master_mint_address,
+        }
+    }
+}
+
 #[derive(Accounts)]
 #[instruction(campaign_id: u8)]
 pub struct GetPlayerProgress<'info> {
-    #[account(mut)]
-    pub player: Signer<'info>,
+    /// CHECK: Only used to derive the player progress PDA
+    pub player: UncheckedAccount<'info>,
 
     #[account(
-        mut,
-        seeds = [b"progress", campaign_id.to_le_bytes().as_ref(), player.key().as_ref()],
+        seeds = [PROGRESS_SEED, campaign_id.to_le_bytes().as_ref(), player.key().as_ref()],
         bump
     )]
-    pub player_progress: Account<'info, PlayerProgress>,
+    /// CHECK: The progress may not exist yet, its address is checked through the seeds
+    pub player_progress: UncheckedAccount<'info>,
 }
 
 impl<'info> GetPlayerProgress<'info> {
-    pub fn get_player_progress(&mut self, campaign_id: u8) -> Result<()> {
+    pub fn get_player_progress(&self, campaign_id: u8) -> Result<PlayerProgressView> {
+        // Players who have not completed a bug in the campaign have no progress account.
+        let progress = if self.player_progress.data_is_empty() {
+            PlayerProgressView {
+                player: self.player.key(),
+                campaign_id,
+                completed_bugs: Vec::new(),
+                total_completed_bugs: 0,
+                total_score: 0,
+                master_mint_address: None,
+            }
+        } else {
+            let data = self.player_progress.try_borrow_data()?;
+            PlayerProgressView::from(&PlayerProgress::try_deserialize(&mut &data[..])?)
+        };
+
         emit!(PlayerProgressEvent {
             player: self.player.key(),
             campaign_id,
-            completed_bugs: self.player_progress.completed_bugs.clone(),
-            total_completed_bugs: self.player_progress.total_completed_bugs
+            completed_bugs: progress.completed_bugs.clone(),
+            total_completed_bugs: progress.total_completed_bugs
         });
 
-        Ok(())
+        Ok(progress)
     }
 }
 
-pub fn handler(ctx: Context<GetPlayerProgress>, campaign_id: u8) -> Result<()> {
+pub(crate) fn handler(
+    ctx: Context<GetPlayerProgress>,
+    campaign_id: u8,
+) -> Result<PlayerProgressView> {
     ctx.accounts.get_player_progress(campaign_id)
 }
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ErrorCode, PlayerProgress, PlayerProgressView, ID, MAX_BATCH_CAMPAIGNS, PROGRESS_SEED,
+};
+
+#[derive(Accounts)]
+pub struct GetPlayerProgressBatch<'info> {
+    /// CHECK: Only used to derive the player progress PDAs
+    pub player: UncheckedAccount<'info>,
+}
+
+impl<'info> GetPlayerProgressBatch<'info> {
+    pub fn get_player_progress_batch(
+        &self,
+        campaign_ids: Vec<u8>,
+        progress_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<Vec<PlayerProgressView>> {
+        require!(
+            campaign_ids.len() <= MAX_BATCH_CAMPAIGNS,
+            ErrorCode::BatchTooLarge
+        );
+        require!(
+            progress_accounts.len() == campaign_ids.len(),
+            ErrorCode::InvalidProgressAccounts
+        );
+
+        let player = self.player.key();
+
+        campaign_ids
+            .into_iter()
+            .zip(progress_accounts.iter())
+            .map(|(campaign_id, progress_account)| {
+                let (player_progress_key, _) = Pubkey::find_program_address(
+                    &[PROGRESS_SEED, &campaign_id.to_le_bytes(), player.as_ref()],
+                    &ID,
+                );
+                require_keys_eq!(
+                    progress_account.key(),
+                    player_progress_key,
+                    ErrorCode::InvalidProgressAccounts
+                );
+
+                // Campaigns the player has not played yet have no progress account.
+                if progress_account.data_is_empty() {
+                    return Ok(PlayerProgressView {
+                        player,
+                        campaign_id,
+                        completed_bugs: Vec::new(),
+                        total_completed_bugs: 0,
+                        total_score: 0,
+                        master_mint_address: None,
+                    });
+                }
+
+                let player_progress = Account::<PlayerProgress>::try_from(progress_account)?;
+                Ok(PlayerProgressView::from(&*player_progress))
+            })
+            .collect()
+    }
+}
+
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, GetPlayerProgressBatch<'info>>,
+    campaign_ids: Vec<u8>,
+) -> Result<Vec<PlayerProgressView>> {
+    ctx.accounts
+        .get_player_progress_batch(campaign_ids, ctx.remaining_accounts)
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::PlayerProgress;
+use crate::{PlayerProgress, PROGRESS_SEED};
 
 #[event]
 pub struct CompletedBugEvent {
@@ -10,23 +10,39 @@ pub struct CompletedBugEvent {
     pub completed: bool,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CompletedBugView {
+    pub player: Pubkey,
+    pub campaign_id: u8,
+    pub bug_id: u8,
+    pub completed: bool,
+}
+
 #[derive(Accounts)]
 #[instruction(campaign_id: u8, bug_id: u8)]
 pub struct HasCompletedBug<'info> {
-    #[account(mut)]
-    pub player: Signer<'info>,
+    /// CHECK: Only used to derive the player progress PDA
+    pub player: UncheckedAccount<'info>,
 
     #[account(
-        mut,
-        seeds = [b"progress", campaign_id.to_le_bytes().as_ref(), player.key().as_ref()],
+        seeds = [PROGRESS_SEED, campaign_id.to_le_bytes().as_ref(), player.key().as_ref()],
         bump
     )]
-    pub player_progress: Account<'info, PlayerProgress>,
+    /// CHECK: The progress may not exist yet, its address is checked through the seeds
+    pub player_progress: UncheckedAccount<'info>,
 }
 
 impl<'info> HasCompletedBug<'info> {
-    pub fn has_completed_bug(&mut self, campaign_id: u8, bug_id: u8) -> Result<()> {
-        let completed = self.player_progress.completed_bugs.contains(&bug_id);
+    pub fn has_completed_bug(&self, campaign_id: u8, bug_id: u8) -> Result<CompletedBugView> {
+        // Players who have not completed a bug in the campaign have no progress account.
+        let completed = if self.player_progress.data_is_empty() {
+            false
+        } else {
+            let data = self.player_progress.try_borrow_data()?;
+            PlayerProgress::try_deserialize(&mut &data[..])?
+                .completed_bugs
+                .contains(&bug_id)
+        };
 
         emit!(CompletedBugEvent {
             player: self.player.key(),
@@ -35,10 +51,19 @@ impl<'info> HasCompletedBug<'info> {
             completed
         });
 
-        Ok(())
+        Ok(CompletedBugView {
+            player: self.player.key(),
+            campaign_id,
+            bug_id,
+            completed,
+        })
     }
 }
 
-pub fn handler(ctx: Context<HasCompletedBug>, campaign_id: u8, bug_id: u8) -> Result<()> {
+pub(crate) fn handler(
+    ctx: Context<HasCompletedBug>,
+    campaign_id: u8,
+    bug_id: u8,
+) -> Result<CompletedBugView> {
     ctx.accounts.has_completed_bug(campaign_id, bug_id)
 }
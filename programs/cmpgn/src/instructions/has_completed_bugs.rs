@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, CompletedBugView, PlayerProgress, MAX_BUG_ID, PROGRESS_SEED};
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u8)]
+pub struct HasCompletedBugs<'info> {
+    /// CHECK: Only used to derive the player progress PDA
+    pub player: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PROGRESS_SEED, campaign_id.to_le_bytes().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    /// CHECK: The progress may not exist yet, its address is checked through the seeds
+    pub player_progress: UncheckedAccount<'info>,
+}
+
+impl<'info> HasCompletedBugs<'info> {
+    pub fn has_completed_bugs(
+        &self,
+        campaign_id: u8,
+        bug_ids: Vec<u8>,
+    ) -> Result<Vec<CompletedBugView>> {
+        require!(
+            bug_ids.len() <= MAX_BUG_ID as usize,
+            ErrorCode::BatchTooLarge
+        );
+
+        // Players who have not completed a bug in the campaign have no progress account.
+        let completed_bugs = if self.player_progress.data_is_empty() {
+            Vec::new()
+        } else {
+            let data = self.player_progress.try_borrow_data()?;
+            PlayerProgress::try_deserialize(&mut &data[..])?.completed_bugs
+        };
+
+        Ok(bug_ids
+            .into_iter()
+            .map(|bug_id| CompletedBugView {
+                player: self.player.key(),
+                campaign_id,
+                bug_id,
+                completed: completed_bugs.contains(&bug_id),
+            })
+            .collect())
+    }
+}
+
+pub(crate) fn handler(
+    ctx: Context<HasCompletedBugs>,
+    campaign_id: u8,
+    bug_ids: Vec<u8>,
+) -> Result<Vec<CompletedBugView>> {
+    ctx.accounts.has_completed_bugs(campaign_id, bug_ids)
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::Campaign;
+use crate::{Campaign, ALL_ATTRIBUTES, CAMPAIGN_SEED, MAX_BUG_ID};
 
 #[derive(Accounts)]
 #[instruction(campaign_id: u8)]
@@ -11,7 +11,7 @@ pub struct InitializeCampaign<'info> {
     #[account(
         init,
         payer = game_authority,
-        seeds = [b"campaign", campaign_id.to_le_bytes().as_ref()],
+        seeds = [CAMPAIGN_SEED, campaign_id.to_le_bytes().as_ref()],
         bump,
         space = Campaign::DISCRIMINATOR.len() + Campaign::INIT_SPACE,
     )]
@@ -23,8 +23,14 @@ impl<'info> InitializeCampaign<'info> {
     pub fn initialize(&mut self, campaign_id: u8, bumps: &InitializeCampaignBumps) -> Result<()> {
         self.campaign.set_inner(Campaign {
             game_authority: self.game_authority.key(),
-            campaign_id: campaign_id,
+            campaign_id,
             total_completions: 0,
+            bug_solvers: [0; MAX_BUG_ID as usize],
+            collections: Vec::new(),
+            mint_price: 0,
+            payment_mint: None,
+            sponsors: Vec::new(),
+            attribute_schema: ALL_ATTRIBUTES,
             bump: bumps.campaign,
         });
 
@@ -32,7 +38,7 @@ impl<'info> InitializeCampaign<'info> {
     }
 }
 
-pub fn handler(ctx: Context<InitializeCampaign>, campaign_id: u8) -> Result<()> {
+pub(crate) fn handler(ctx: Context<InitializeCampaign>, campaign_id: u8) -> Result<()> {
     ctx.accounts.initialize(campaign_id, &ctx.bumps)?;
     Ok(())
 }
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    instructions::CreateV2CpiBuilder,
+    types::{Attribute, Attributes, Plugin, PluginAuthorityPair},
+    ID as CORE_PROGRAM_ID,
+};
+
+use crate::{
+    error::ErrorCode, instructions::mint_nft::soulbound_plugins, state::CollectionAuthority,
+    Campaign, PlayerProgress, CAMPAIGN_SEED, COLLECTION_SEED, MAX_BUG_ID, PROGRESS_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u8)]
+pub struct MintCampaignMaster<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = asset.data_is_empty() @ ErrorCode::AssetAlreadyInitialized
+    )]
+    pub asset: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = collection.owner == &CORE_PROGRAM_ID @ ErrorCode::InvalidCollection,
+        constraint = !collection.data_is_empty() @ ErrorCode::CollectionNotInitialized,
+        constraint = collection.key() == collection_authority.collection @ ErrorCode::InvalidCollection
+    )]
+    /// CHECK: This will also be checked by core
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [COLLECTION_SEED, collection.key().as_ref()],
+        bump,
+        constraint = collection_authority.campaign_id == campaign_id @ ErrorCode::InvalidCollection,
+    )]
+    pub collection_authority: Box<Account<'info, CollectionAuthority>>,
+
+    #[account(
+        seeds = [CAMPAIGN_SEED, campaign_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = campaign.collections.contains(&collection.key()) @ ErrorCode::InvalidCollection,
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    #[account(
+        mut,
+        seeds = [PROGRESS_SEED, campaign_id.to_le_bytes().as_ref(), player.key().as_ref()],
+        bump,
+        constraint = player_progress.master_mint_address.is_none() @ ErrorCode::MasterNftAlreadyMinted,
+    )]
+    pub player_progress: Box<Account<'info, PlayerProgress>>,
+
+    #[account(address = CORE_PROGRAM_ID)]
+    /// CHECK: This will also be checked by core
+    pub core_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MintCampaignMaster<'info> {
+    pub fn mint_campaign_master(&mut self, campaign_id: u8) -> Result<()> {
+        require!(
+            (1..=MAX_BUG_ID).all(|bug_id| self.player_progress.completed_bugs.contains(&bug_id)),
+            ErrorCode::CampaignNotFullyCompleted
+        );
+
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            COLLECTION_SEED,
+            &self.collection.key().to_bytes(),
+            &[self.collection_authority.bump],
+        ]];
+
+        let current_timestamp = Clock::get()?.unix_timestamp;
+
+        let mut plugins = vec![PluginAuthorityPair {
+            plugin: Plugin::Attributes(Attributes {
+                attribute_list: vec![
+                    Attribute {
+                        key: "Type".to_string(),
+                        value: "Campaign Master".to_string(),
+                    },
+                    Attribute {
+                        key: "Creator".to_string(),
+                        value: self.collection_authority.creator.to_string(),
+                    },
+                    Attribute {
+                        key: "Minter".to_string(),
+                        value: self.player.key().to_string(),
+                    },
+                    Attribute {
+                        key: "Collection".to_string(),
+                        value: self.collection.key().to_string(),
+                    },
+                    Attribute {
+                        key: "Mint Timestamp".to_string(),
+                        value: current_timestamp.to_string(),
+                    },
+                    Attribute {
+                        key: "Campaign ID".to_string(),
+                        value: campaign_id.to_string(),
+                    },
+                    Attribute {
+                        key: "Total Score".to_string(),
+                        value: self.player_progress.total_score.to_string(),
+                    },
+                ],
+            }),
+            authority: None,
+        }];
+
+        if self.collection_authority.soulbound {
+            plugins.extend(soulbound_plugins());
+        }
+
+        CreateV2CpiBuilder::new(&self.core_program.to_account_info())
+            .asset(&self.asset.to_account_info())
+            .collection(Some(&self.collection.to_account_info()))
+            .authority(Some(&self.collection_authority.to_account_info()))
+            .payer(&self.player.to_account_info())
+            .owner(Some(&self.player.to_account_info()))
+            .update_authority(None)
+            .system_program(&self.system_program.to_account_info())
+            .name(self.collection_authority.master_nft_name.clone())
+            .uri(self.collection_authority.master_nft_uri.clone())
+            .plugins(plugins)
+            .external_plugin_adapters(vec![])
+            .invoke_signed(signer_seeds)?;
+
+        self.player_progress.master_mint_address = Some(self.asset.key());
+        Ok(())
+    }
+}
+
+pub(crate) fn handler(ctx: Context<MintCampaignMaster>, campaign_id: u8) -> Result<()> {
+    ctx.accounts.mint_campaign_master(campaign_id)?;
+
+    Ok(())
+}
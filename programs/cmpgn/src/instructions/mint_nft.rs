@@ -1,18 +1,50 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
 use mpl_core::{
     instructions::CreateV2CpiBuilder,
-    types::{Attribute, Attributes, Plugin, PluginAuthorityPair},
+    types::{
+        Attribute, Attributes, PermanentBurnDelegate, PermanentFreezeDelegate, Plugin,
+        PluginAuthority, PluginAuthorityPair,
+    },
     ID as CORE_PROGRAM_ID,
 };
 
-use crate::{error::ErrorCode, state::CollectionAuthority, CampaignCompletion};
+use crate::{
+    error::ErrorCode, state::CollectionAuthority, BugMetadata, Campaign, CampaignCompletion,
+    Treasury, ATTRIBUTE_ATTEMPTS, ATTRIBUTE_BUG_ID, ATTRIBUTE_CAMPAIGN_ID, ATTRIBUTE_COLLECTION,
+    ATTRIBUTE_COMPLETION_TIME, ATTRIBUTE_CREATOR, ATTRIBUTE_DAILY_SOLVE, ATTRIBUTE_MINTER,
+    ATTRIBUTE_MINT_TIMESTAMP, ATTRIBUTE_RARITY, ATTRIBUTE_RECIPIENT, ATTRIBUTE_SCORE,
+    ATTRIBUTE_SOLVER, ATTRIBUTE_SOLVE_DURATION, ATTRIBUTE_START_TIME, BUG_METADATA_SEED,
+    CAMPAIGN_SEED, COLLECTION_SEED, COMPLETION_SEED, TREASURY_SEED,
+};
+
+pub(crate) fn soulbound_plugins() -> Vec<PluginAuthorityPair> {
+    vec![
+        PluginAuthorityPair {
+            plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate { frozen: true }),
+            authority: Some(PluginAuthority::UpdateAuthority),
+        },
+        PluginAuthorityPair {
+            plugin: Plugin::PermanentBurnDelegate(PermanentBurnDelegate {}),
+            authority: Some(PluginAuthority::UpdateAuthority),
+        },
+    ]
+}
 
 #[derive(Accounts)]
-#[instruction(bug_id: u8, campaign_id: u8)]
+#[instruction(campaign_id: u8, bug_id: u8)]
 pub struct MintNft<'info> {
     #[account(mut)]
     pub player: Signer<'info>,
 
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
     #[account(
         mut,
         constraint = asset.data_is_empty() @ ErrorCode::AssetAlreadyInitialized
@@ -29,96 +61,335 @@ pub struct MintNft<'info> {
     pub collection: UncheckedAccount<'info>,
 
     #[account(
-        seeds = [b"collection", collection.key().as_ref()],
+        seeds = [COLLECTION_SEED, collection.key().as_ref()],
         bump,
+        constraint = collection_authority.campaign_id == campaign_id @ ErrorCode::InvalidCollection,
     )]
     pub collection_authority: Box<Account<'info, CollectionAuthority>>,
 
+    #[account(
+        seeds = [CAMPAIGN_SEED, campaign_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = payer.as_ref().is_none_or(|payer| payer.key() == player.key() || campaign.sponsors.contains(&payer.key())) @ ErrorCode::UnauthorizedPayer,
+        constraint = campaign.collections.contains(&collection.key()) @ ErrorCode::InvalidCollection,
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    #[account(
+        seeds = [BUG_METADATA_SEED, collection.key().as_ref(), bug_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    /// CHECK: Empty until the authority sets metadata for the bug, decoded when minting
+    pub bug_metadata: UncheckedAccount<'info>,
+
     #[account(
         mut,
-        seeds = [b"completion", campaign_id.to_le_bytes().as_ref(), player.key().as_ref(), bug_id.to_le_bytes().as_ref()],
+        seeds = [COMPLETION_SEED, campaign_id.to_le_bytes().as_ref(), player.key().as_ref(), bug_id.to_le_bytes().as_ref()],
         bump,
         constraint = campaign_completion.player == player.key() @ ErrorCode::UnauthorizedPlayer,
         constraint = campaign_completion.campaign_end.is_some() @ ErrorCode::CampaignNotCompleted,
         constraint = campaign_completion.nft_mint_address.is_none() @ ErrorCode::NftAlreadyMinted,
+        constraint = !campaign_completion.revoked @ ErrorCode::CompletionRevoked,
     )]
     pub campaign_completion: Box<Account<'info, CampaignCompletion>>,
 
+    /// CHECK: Any wallet chosen by the player to own the NFT
+    pub recipient: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, campaign_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub treasury: Option<Box<Account<'info, Treasury>>>,
+
+    #[account(mut)]
+    pub player_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub treasury_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub payment_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
     #[account(address = CORE_PROGRAM_ID)]
     /// CHECK: This will also be checked by core
     pub core_program: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
-impl<'info> MintNft<'info> {
-    pub fn mint_nft(
-        &mut self,
-        campaign_id: u8,
-        bug_id: u8,
-        name: String,
-        nft_uri: String,
-    ) -> Result<()> {
+pub(crate) struct CompletionNftMint<'a, 'info> {
+    pub campaign: &'a Campaign,
+    pub core_program: AccountInfo<'info>,
+    pub asset: AccountInfo<'info>,
+    pub collection: AccountInfo<'info>,
+    pub collection_authority: &'a Account<'info, CollectionAuthority>,
+    pub bug_metadata: AccountInfo<'info>,
+    pub player: AccountInfo<'info>,
+    pub recipient: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'a, 'info> CompletionNftMint<'a, 'info> {
+    pub fn mint(&self, bug_id: u8, campaign_completion: &CampaignCompletion) -> Result<()> {
         let signer_seeds: &[&[&[u8]]] = &[&[
-            b"collection",
+            COLLECTION_SEED,
             &self.collection.key().to_bytes(),
             &[self.collection_authority.bump],
         ]];
 
         let current_timestamp = Clock::get()?.unix_timestamp;
 
-        CreateV2CpiBuilder::new(&self.core_program.to_account_info())
-            .asset(&self.asset.to_account_info())
-            .collection(Some(&self.collection.to_account_info()))
+        let campaign_start = campaign_completion.campaign_start.unwrap_or_default();
+        let campaign_end = campaign_completion.campaign_end.unwrap_or_default();
+        let solve_duration = campaign_end - campaign_start;
+
+        // Bugs the authority has not set metadata for use the collection template.
+        let bug_metadata = if self.bug_metadata.data_is_empty() {
+            None
+        } else {
+            let data = self.bug_metadata.try_borrow_data()?;
+            Some(BugMetadata::try_deserialize(&mut &data[..])?)
+        };
+
+        let rarity = bug_metadata
+            .as_ref()
+            .and_then(|bug_metadata| bug_metadata.rarity_tiers.as_ref())
+            .map(|rarity_tiers| (rarity_tiers, rarity_tiers.tier_for(solve_duration)));
+
+        let nft_uri = match (rarity, bug_metadata.as_ref()) {
+            (Some((rarity_tiers, rarity_tier)), _) => rarity_tiers.uri_for(rarity_tier).to_string(),
+            (None, Some(bug_metadata)) => bug_metadata.uri.clone(),
+            (None, None) => self.collection_authority.nft_uri_for(bug_id),
+        };
+
+        let mut attributes = vec![
+            (
+                ATTRIBUTE_CREATOR,
+                "Creator",
+                self.collection_authority.creator.to_string(),
+            ),
+            (ATTRIBUTE_MINTER, "Minter", self.player.key().to_string()),
+            (
+                ATTRIBUTE_RECIPIENT,
+                "Recipient",
+                self.recipient.key().to_string(),
+            ),
+            (
+                ATTRIBUTE_COLLECTION,
+                "Collection",
+                self.collection.key().to_string(),
+            ),
+            (
+                ATTRIBUTE_MINT_TIMESTAMP,
+                "Mint Timestamp",
+                current_timestamp.to_string(),
+            ),
+            (ATTRIBUTE_BUG_ID, "Bug ID", bug_id.to_string()),
+            (
+                ATTRIBUTE_CAMPAIGN_ID,
+                "Campaign ID",
+                self.campaign.campaign_id.to_string(),
+            ),
+            (
+                ATTRIBUTE_START_TIME,
+                "Start Time",
+                campaign_start.to_string(),
+            ),
+            (
+                ATTRIBUTE_COMPLETION_TIME,
+                "Completion Time",
+                campaign_end.to_string(),
+            ),
+            (
+                ATTRIBUTE_SOLVE_DURATION,
+                "Solve Duration",
+                solve_duration.to_string(),
+            ),
+            (
+                ATTRIBUTE_ATTEMPTS,
+                "Attempts",
+                campaign_completion.attempts.to_string(),
+            ),
+            (
+                ATTRIBUTE_DAILY_SOLVE,
+                "Daily Solve",
+                campaign_completion.daily_solve.to_string(),
+            ),
+            (
+                ATTRIBUTE_SCORE,
+                "Score",
+                campaign_completion.score.to_string(),
+            ),
+            (
+                ATTRIBUTE_SOLVER,
+                "Solver",
+                format!("#{}", campaign_completion.solver_number),
+            ),
+        ];
+
+        if let Some((_, rarity_tier)) = rarity {
+            attributes.push((ATTRIBUTE_RARITY, "Rarity", rarity_tier.name().to_string()));
+        }
+
+        let attribute_list = attributes
+            .into_iter()
+            .filter(|(flag, _, _)| self.campaign.attribute_schema & flag != 0)
+            .map(|(_, key, value)| Attribute {
+                key: key.to_string(),
+                value,
+            })
+            .collect();
+
+        let mut plugins = vec![PluginAuthorityPair {
+            plugin: Plugin::Attributes(Attributes { attribute_list }),
+            authority: None,
+        }];
+
+        if self.collection_authority.soulbound {
+            plugins.extend(soulbound_plugins());
+        }
+
+        CreateV2CpiBuilder::new(&self.core_program)
+            .asset(&self.asset)
+            .collection(Some(&self.collection))
             .authority(Some(&self.collection_authority.to_account_info()))
-            .payer(&self.player.to_account_info())
-            .owner(Some(&self.player.to_account_info()))
+            .payer(&self.payer)
+            .owner(Some(&self.recipient))
             .update_authority(None)
-            .system_program(&self.system_program.to_account_info())
-            .name(name)
+            .system_program(&self.system_program)
+            .name(self.collection_authority.nft_name_for(bug_id))
             .uri(nft_uri)
-            .plugins(vec![PluginAuthorityPair {
-                plugin: Plugin::Attributes(Attributes {
-                    attribute_list: vec![
-                        Attribute {
-                            key: "Creator".to_string(),
-                            value: self.collection_authority.creator.to_string(),
-                        },
-                        Attribute {
-                            key: "Minter".to_string(),
-                            value: self.player.key().to_string(),
-                        },
-                        Attribute {
-                            key: "Collection".to_string(),
-                            value: self.collection.key().to_string(),
-                        },
-                        Attribute {
-                            key: "Mint Timestamp".to_string(),
-                            value: current_timestamp.to_string(),
-                        },
-                        Attribute {
-                            key: "Bug ID".to_string(),
-                            value: bug_id.to_string(),
-                        },
-                    ],
-                }),
-                authority: None,
-            }])
+            .plugins(plugins)
             .external_plugin_adapters(vec![])
             .invoke_signed(signer_seeds)?;
 
+        Ok(())
+    }
+}
+
+pub(crate) struct MintFeePayment<'a, 'info> {
+    pub campaign: &'a Campaign,
+    pub player: AccountInfo<'info>,
+    pub treasury: Option<&'a Account<'info, Treasury>>,
+    pub player_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub treasury_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub payment_mint: Option<&'a InterfaceAccount<'info, Mint>>,
+    pub token_program: Option<&'a Interface<'info, TokenInterface>>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'a, 'info> MintFeePayment<'a, 'info> {
+    pub fn pay(&self) -> Result<()> {
+        let mint_price = self.campaign.mint_price;
+        if mint_price == 0 {
+            return Ok(());
+        }
+
+        let treasury = self.treasury.ok_or(ErrorCode::InvalidMintFeeAccounts)?;
+
+        match self.campaign.payment_mint {
+            None => transfer(
+                CpiContext::new(
+                    self.system_program.clone(),
+                    Transfer {
+                        from: self.player.clone(),
+                        to: treasury.to_account_info(),
+                    },
+                ),
+                mint_price,
+            ),
+            Some(payment_mint_key) => {
+                let (
+                    Some(player_token_account),
+                    Some(treasury_token_account),
+                    Some(payment_mint),
+                    Some(token_program),
+                ) = (
+                    self.player_token_account,
+                    self.treasury_token_account,
+                    self.payment_mint,
+                    self.token_program,
+                )
+                else {
+                    return err!(ErrorCode::InvalidMintFeeAccounts);
+                };
+
+                require_keys_eq!(
+                    payment_mint.key(),
+                    payment_mint_key,
+                    ErrorCode::InvalidMintFeeAccounts
+                );
+                require_keys_eq!(
+                    treasury_token_account.owner,
+                    treasury.key(),
+                    ErrorCode::InvalidMintFeeAccounts
+                );
+                require_keys_eq!(
+                    treasury_token_account.mint,
+                    payment_mint_key,
+                    ErrorCode::InvalidMintFeeAccounts
+                );
+
+                transfer_checked(
+                    CpiContext::new(
+                        token_program.to_account_info(),
+                        TransferChecked {
+                            from: player_token_account.to_account_info(),
+                            mint: payment_mint.to_account_info(),
+                            to: treasury_token_account.to_account_info(),
+                            authority: self.player.clone(),
+                        },
+                    ),
+                    mint_price,
+                    payment_mint.decimals,
+                )
+            }
+        }
+    }
+}
+
+impl<'info> MintNft<'info> {
+    pub fn mint_nft(&mut self, _campaign_id: u8, bug_id: u8) -> Result<()> {
+        MintFeePayment {
+            campaign: &self.campaign,
+            player: self.player.to_account_info(),
+            treasury: self.treasury.as_deref(),
+            player_token_account: self.player_token_account.as_deref(),
+            treasury_token_account: self.treasury_token_account.as_deref(),
+            payment_mint: self.payment_mint.as_deref(),
+            token_program: self.token_program.as_ref(),
+            system_program: self.system_program.to_account_info(),
+        }
+        .pay()?;
+
+        CompletionNftMint {
+            campaign: &self.campaign,
+            core_program: self.core_program.to_account_info(),
+            asset: self.asset.to_account_info(),
+            collection: self.collection.to_account_info(),
+            collection_authority: &self.collection_authority,
+            bug_metadata: self.bug_metadata.to_account_info(),
+            player: self.player.to_account_info(),
+            recipient: self.recipient.as_ref().map_or_else(
+                || self.player.to_account_info(),
+                |recipient| recipient.to_account_info(),
+            ),
+            payer: self.payer.as_ref().map_or_else(
+                || self.player.to_account_info(),
+                |payer| payer.to_account_info(),
+            ),
+            system_program: self.system_program.to_account_info(),
+        }
+        .mint(bug_id, &self.campaign_completion)?;
+
         self.campaign_completion.nft_mint_address = Some(self.asset.key());
         Ok(())
     }
 }
 
-pub fn handler(
-    ctx: Context<MintNft>,
-    campaign_id: u8,
-    bug_id: u8,
-    name: String,
-    nft_uri: String,
-) -> Result<()> {
-    ctx.accounts.mint_nft(campaign_id, bug_id, name, nft_uri)?;
+pub(crate) fn handler(ctx: Context<MintNft>, campaign_id: u8, bug_id: u8) -> Result<()> {
+    ctx.accounts.mint_nft(campaign_id, bug_id)?;
 
     Ok(())
 }
@@ -1,19 +1,65 @@
+pub mod add_collection_plugin;
+pub mod burn_nft;
+pub mod check_completion;
+pub mod claim_bounty;
+pub mod claim_reward;
+pub mod complete_and_mint;
+pub mod configure_rewards;
+pub mod configure_treasury;
 pub mod create_collection;
+pub mod create_forge_recipe;
+pub mod forge_nft;
+pub mod fund_bounty;
 pub mod get_campaign_stats;
 pub mod get_daily_bug;
 pub mod get_player_progress;
+pub mod get_player_progress_batch;
 pub mod has_completed_bug;
+pub mod has_completed_bugs;
 pub mod initialize_campaign;
+pub mod mint_campaign_master;
 pub mod mint_nft;
+pub mod reclaim_bounty;
 pub mod record_campaign_completion;
+pub mod remove_collection_plugin;
+pub mod revoke_completion;
+pub mod set_attribute_schema;
+pub mod set_bug_metadata;
+pub mod set_sponsors;
 pub mod start_campaign;
+pub mod update_collection;
+pub mod update_nft_attributes;
+pub mod withdraw_treasury;
 
+pub use add_collection_plugin::*;
+pub use burn_nft::*;
+pub use check_completion::*;
+pub use claim_bounty::*;
+pub use claim_reward::*;
+pub use complete_and_mint::*;
+pub use configure_rewards::*;
+pub use configure_treasury::*;
 pub use create_collection::*;
+pub use create_forge_recipe::*;
+pub use forge_nft::*;
+pub use fund_bounty::*;
 pub use get_campaign_stats::*;
 pub use get_daily_bug::*;
 pub use get_player_progress::*;
+pub use get_player_progress_batch::*;
 pub use has_completed_bug::*;
+pub use has_completed_bugs::*;
 pub use initialize_campaign::*;
+pub use mint_campaign_master::*;
 pub use mint_nft::*;
+pub use reclaim_bounty::*;
 pub use record_campaign_completion::*;
+pub use remove_collection_plugin::*;
+pub use revoke_completion::*;
+pub use set_attribute_schema::*;
+pub use set_bug_metadata::*;
+pub use set_sponsors::*;
 pub use start_campaign::*;
+pub use update_collection::*;
+pub use update_nft_attributes::*;
+pub use withdraw_treasury::*;
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::{error::ErrorCode, Bounty, BOUNTY_SEED};
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u8, bug_id: u8)]
+pub struct ReclaimBounty<'info> {
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BOUNTY_SEED, campaign_id.to_le_bytes().as_ref(), bug_id.to_le_bytes().as_ref(), sponsor.key().as_ref(), bounty.nonce.to_le_bytes().as_ref()],
+        bump,
+        has_one = sponsor,
+        close = sponsor,
+    )]
+    pub bounty: Box<Account<'info, Bounty>>,
+
+    #[account(mut)]
+    pub escrow_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub sponsor_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub payment_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+impl<'info> ReclaimBounty<'info> {
+    pub fn reclaim_bounty(&mut self, campaign_id: u8, bug_id: u8) -> Result<()> {
+        require!(
+            Clock::get()?.unix_timestamp >= self.bounty.expires_at,
+            ErrorCode::BountyNotExpired
+        );
+
+        // Unclaimed SOL is returned when the bounty account is closed.
+        let Some(payment_mint_key) = self.bounty.payment_mint else {
+            return Ok(());
+        };
+
+        let (
+            Some(escrow_token_account),
+            Some(sponsor_token_account),
+            Some(payment_mint),
+            Some(token_program),
+        ) = (
+            self.escrow_token_account.as_ref(),
+            self.sponsor_token_account.as_ref(),
+            self.payment_mint.as_ref(),
+            self.token_program.as_ref(),
+        )
+        else {
+            return err!(ErrorCode::InvalidBountyAccounts);
+        };
+
+        require_keys_eq!(
+            payment_mint.key(),
+            payment_mint_key,
+            ErrorCode::InvalidBountyAccounts
+        );
+        require_keys_eq!(
+            escrow_token_account.owner,
+            self.bounty.key(),
+            ErrorCode::InvalidBountyAccounts
+        );
+
+        let sponsor = self.sponsor.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            BOUNTY_SEED,
+            &campaign_id.to_le_bytes(),
+            &bug_id.to_le_bytes(),
+            sponsor.as_ref(),
+            &self.bounty.nonce.to_le_bytes(),
+            &[self.bounty.bump],
+        ]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                TransferChecked {
+                    from: escrow_token_account.to_account_info(),
+                    mint: payment_mint.to_account_info(),
+                    to: sponsor_token_account.to_account_info(),
+                    authority: self.bounty.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            escrow_token_account.amount,
+            payment_mint.decimals,
+        )?;
+
+        close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: escrow_token_account.to_account_info(),
+                destination: self.sponsor.to_account_info(),
+                authority: self.bounty.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+
+        Ok(())
+    }
+}
+
+pub(crate) fn handler(ctx: Context<ReclaimBounty>, campaign_id: u8, bug_id: u8) -> Result<()> {
+    ctx.accounts.reclaim_bounty(campaign_id, bug_id)?;
+
+    Ok(())
+}
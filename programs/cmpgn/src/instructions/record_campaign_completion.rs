@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::error::ErrorCode;
-use crate::{Campaign, CampaignCompletion, PlayerProgress};
+use crate::{
+    Campaign, CampaignCompletion, DailyBug, PlayerProgress, BASE_COMPLETION_SCORE, CAMPAIGN_SEED,
+    COMPLETION_SEED, DAILY_SOLVE_MULTIPLIER, MAX_BUG_ID, PROGRESS_SEED,
+};
 
 #[derive(Accounts)]
 #[instruction(campaign_id: u8, bug_id: u8)]
@@ -9,31 +12,107 @@ pub struct RecordCampaignCompletion<'info> {
     #[account(mut)]
     pub player: Signer<'info>,
 
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
     #[account(
         mut,
-        seeds = [b"completion", campaign_id.to_le_bytes().as_ref(), player.key().as_ref(), bug_id.to_le_bytes().as_ref()],
+        seeds = [COMPLETION_SEED, campaign_id.to_le_bytes().as_ref(), player.key().as_ref(), bug_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub campaign_completion: Box<Account<'info, CampaignCompletion>>,
 
     #[account(
         init_if_needed,
-        payer = player,
+        payer = payer.as_ref().map_or_else(|| player.to_account_info(), |payer| payer.to_account_info()),
         space = PlayerProgress::DISCRIMINATOR.len() + PlayerProgress::INIT_SPACE,
-        seeds = [b"progress", campaign_id.to_le_bytes().as_ref(), player.key().as_ref()],
+        seeds = [PROGRESS_SEED, campaign_id.to_le_bytes().as_ref(), player.key().as_ref()],
         bump
     )]
     pub player_progress: Account<'info, PlayerProgress>,
 
     #[account(
         mut,
-        seeds = [b"campaign", campaign_id.to_le_bytes().as_ref()],
+        seeds = [CAMPAIGN_SEED, campaign_id.to_le_bytes().as_ref()],
         bump,
+        constraint = payer.as_ref().is_none_or(|payer| payer.key() == player.key() || campaign.sponsors.contains(&payer.key())) @ ErrorCode::UnauthorizedPayer,
     )]
     pub campaign: Box<Account<'info, Campaign>>,
     pub system_program: Program<'info, System>,
 }
 
+pub(crate) fn record_completion(
+    player: Pubkey,
+    campaign_id: u8,
+    bug_id: u8,
+    campaign: &mut Campaign,
+    campaign_completion: &mut CampaignCompletion,
+    player_progress: &mut PlayerProgress,
+    player_progress_bump: u8,
+) -> Result<()> {
+    require!((1..=MAX_BUG_ID).contains(&bug_id), ErrorCode::InvalidBugId);
+
+    require!(
+        campaign_id == campaign.campaign_id,
+        ErrorCode::InvalidCampaignId
+    );
+
+    require!(
+        campaign_completion.player == player,
+        ErrorCode::UnauthorizedPlayer
+    );
+
+    require!(
+        campaign_completion.campaign_start.is_some(),
+        ErrorCode::CampaignNotStarted
+    );
+
+    require!(
+        campaign_completion.campaign_end.is_none(),
+        ErrorCode::CampaignAlreadyCompleted
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    if player_progress.player == Pubkey::default() {
+        *player_progress = PlayerProgress {
+            player,
+            campaign_id,
+            completed_bugs: Vec::new(),
+            total_completed_bugs: 0,
+            total_score: 0,
+            master_mint_address: None,
+            bump: player_progress_bump,
+        };
+    }
+
+    let daily_solve = DailyBug::bug_id_at(now) == bug_id;
+    let score = if daily_solve {
+        BASE_COMPLETION_SCORE * DAILY_SOLVE_MULTIPLIER
+    } else {
+        BASE_COMPLETION_SCORE
+    };
+
+    let bug_solvers = &mut campaign.bug_solvers[(bug_id - 1) as usize];
+    *bug_solvers += 1;
+
+    campaign_completion.campaign_end = Some(now);
+    campaign_completion.timestamp = Some(now);
+    campaign_completion.daily_solve = daily_solve;
+    campaign_completion.score = score;
+    campaign_completion.solver_number = *bug_solvers;
+
+    if !player_progress.completed_bugs.contains(&bug_id) {
+        player_progress.completed_bugs.push(bug_id);
+        player_progress.total_completed_bugs += 1;
+        player_progress.total_score += score;
+
+        campaign.total_completions += 1;
+    }
+
+    Ok(())
+}
+
 impl<'info> RecordCampaignCompletion<'info> {
     pub fn record_campaign_completion(
         &mut self,
@@ -41,55 +120,23 @@ impl<'info> RecordCampaignCompletion<'info> {
         bug_id: u8,
         bumps: &RecordCampaignCompletionBumps,
     ) -> Result<()> {
-        require!(bug_id >= 1 && bug_id <= 20, ErrorCode::InvalidBugId);
-
-        require!(
-            campaign_id == self.campaign.campaign_id,
-            ErrorCode::InvalidCampaignId
-        );
-
-        require!(
-            self.campaign_completion.player == self.player.key(),
-            ErrorCode::UnauthorizedPlayer
-        );
-
-        require!(
-            self.campaign_completion.campaign_start.is_some(),
-            ErrorCode::CampaignNotStarted
-        );
-
-        require!(
-            self.campaign_completion.campaign_end.is_none(),
-            ErrorCode::CampaignAlreadyCompleted
-        );
-
-        let now = Clock::get()?.unix_timestamp;
-
-        if self.player_progress.player == Pubkey::default() {
-            self.player_progress.set_inner(PlayerProgress {
-                player: self.player.key(),
-                campaign_id: campaign_id,
-                completed_bugs: Vec::new(),
-                total_completed_bugs: 0,
-                bump: bumps.player_progress,
-            });
-        }
-
-        self.campaign_completion.campaign_end = Some(now);
-        self.campaign_completion.timestamp = Some(now);
-
-        if !self.player_progress.completed_bugs.contains(&bug_id) {
-            self.player_progress.completed_bugs.push(bug_id);
-            self.player_progress.total_completed_bugs += 1;
-
-            self.campaign.total_completions += 1;
-        }
-
-        Ok(())
+        record_completion(
+            self.player.key(),
+            campaign_id,
+            bug_id,
+            &mut self.campaign,
+            &mut self.campaign_completion,
+            &mut self.player_progress,
+            bumps.player_progress,
+        )
     }
 }
 
-pub fn handler(ctx: Context<RecordCampaignCompletion>, campaign_id: u8, bug_id: u8) -> Result<()> {
+pub(crate) fn handler(
+    ctx: Context<RecordCampaignCompletion>,
+    campaign_id: u8,
+    bug_id: u8,
+) -> Result<()> {
     ctx.accounts
         .record_campaign_completion(campaign_id, bug_id, &ctx.bumps)?;
 
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    instructions::RemoveCollectionPluginV1CpiBuilder, types::PluginType, ID as CORE_PROGRAM_ID,
+};
+
+use crate::{error::ErrorCode, state::CollectionAuthority, COLLECTION_SEED};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub enum CollectionPluginKind {
+    Royalties,
+    Attributes,
+}
+
+impl From<CollectionPluginKind> for PluginType {
+    fn from(kind: CollectionPluginKind) -> Self {
+        match kind {
+            CollectionPluginKind::Royalties => PluginType::Royalties,
+            CollectionPluginKind::Attributes => PluginType::Attributes,
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct RemoveCollectionPlugin<'info> {
+    #[account(
+        mut,
+        constraint = collection_authority.creator == creator.key() @ ErrorCode::NotAuthorized
+    )]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = collection.owner == &CORE_PROGRAM_ID @ ErrorCode::InvalidCollection,
+        constraint = collection.key() == collection_authority.collection @ ErrorCode::InvalidCollection
+    )]
+    /// CHECK: This will also be checked by core
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [COLLECTION_SEED, collection.key().as_ref()],
+        bump = collection_authority.bump,
+    )]
+    pub collection_authority: Box<Account<'info, CollectionAuthority>>,
+
+    #[account(address = CORE_PROGRAM_ID)]
+    /// CHECK: This will also be checked by core
+    pub core_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RemoveCollectionPlugin<'info> {
+    pub fn remove_collection_plugin(&mut self, plugin: CollectionPluginKind) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            COLLECTION_SEED,
+            &self.collection.key().to_bytes(),
+            &[self.collection_authority.bump],
+        ]];
+
+        RemoveCollectionPluginV1CpiBuilder::new(&self.core_program.to_account_info())
+            .collection(&self.collection.to_account_info())
+            .payer(&self.creator.to_account_info())
+            .authority(Some(&self.collection_authority.to_account_info()))
+            .system_program(&self.system_program.to_account_info())
+            .plugin_type(plugin.into())
+            .invoke_signed(signer_seeds)?;
+
+        Ok(())
+    }
+}
+
+pub(crate) fn handler(
+    ctx: Context<RemoveCollectionPlugin>,
+    plugin: CollectionPluginKind,
+) -> Result<()> {
+    ctx.accounts.remove_collection_plugin(plugin)?;
+
+    Ok(())
+}
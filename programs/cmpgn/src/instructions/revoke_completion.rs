@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use mpl_core::{accounts::BaseAssetV1, instructions::BurnV1CpiBuilder, ID as CORE_PROGRAM_ID};
+
+use crate::{
+    error::ErrorCode, state::CollectionAuthority, Campaign, CampaignCompletion, PlayerProgress,
+    CAMPAIGN_SEED, COLLECTION_SEED, COMPLETION_SEED, PROGRESS_SEED,
+};
+
+#[event]
+pub struct CompletionRevokedEvent {
+    pub player: Pubkey,
+    pub campaign_id: u8,
+    pub bug_id: u8,
+    pub asset: Option<Pubkey>,
+    pub revoked_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u8, bug_id: u8)]
+pub struct RevokeCompletion<'info> {
+    #[account(mut)]
+    pub game_authority: Signer<'info>,
+
+    /// CHECK: Only used to derive the player's completion and progress PDAs
+    pub player: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, campaign_id.to_le_bytes().as_ref()],
+        bump,
+        has_one = game_authority
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    #[account(
+        mut,
+        seeds = [COMPLETION_SEED, campaign_id.to_le_bytes().as_ref(), player.key().as_ref(), bug_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = campaign_completion.campaign_end.is_some() @ ErrorCode::CampaignNotCompleted,
+        constraint = !campaign_completion.revoked @ ErrorCode::CompletionRevoked,
+    )]
+    pub campaign_completion: Box<Account<'info, CampaignCompletion>>,
+
+    #[account(
+        mut,
+        seeds = [PROGRESS_SEED, campaign_id.to_le_bytes().as_ref(), player.key().as_ref()],
+        bump,
+    )]
+    pub player_progress: Box<Account<'info, PlayerProgress>>,
+
+    #[account(mut)]
+    /// CHECK: Matched against the completion's mint address and checked by core
+    pub asset: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Matched against the collection authority and checked by core
+    pub collection: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [COLLECTION_SEED, collection_authority.collection.as_ref()],
+        bump = collection_authority.bump,
+    )]
+    pub collection_authority: Option<Box<Account<'info, CollectionAuthority>>>,
+
+    #[account(address = CORE_PROGRAM_ID)]
+    /// CHECK: This will also be checked by core
+    pub core_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RevokeCompletion<'info> {
+    pub fn revoke_completion(&mut self, campaign_id: u8, bug_id: u8) -> Result<()> {
+        let revoked_asset = match self.campaign_completion.nft_mint_address {
+            Some(asset_key) if self.burn_asset(campaign_id, asset_key)? => Some(asset_key),
+            _ => None,
+        };
+
+        let score = self.campaign_completion.score;
+        if revoked_asset.is_some() {
+            self.campaign_completion.nft_mint_address = None;
+        }
+        self.campaign_completion.revoked = true;
+
+        if self.player_progress.completed_bugs.contains(&bug_id) {
+            self.player_progress
+                .completed_bugs
+                .retain(|completed| *completed != bug_id);
+            self.player_progress.total_completed_bugs =
+                self.player_progress.total_completed_bugs.saturating_sub(1);
+            self.player_progress.total_score =
+                self.player_progress.total_score.saturating_sub(score);
+
+            self.campaign.total_completions = self.campaign.total_completions.saturating_sub(1);
+        }
+
+        emit!(CompletionRevokedEvent {
+            player: self.player.key(),
+            campaign_id,
+            bug_id,
+            asset: revoked_asset,
+            revoked_by: self.game_authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Returns whether the asset is gone from its holder's wallet.
+    fn burn_asset(&self, campaign_id: u8, asset_key: Pubkey) -> Result<bool> {
+        let (Some(asset), Some(collection), Some(collection_authority)) = (
+            self.asset.as_ref(),
+            self.collection.as_ref(),
+            self.collection_authority.as_ref(),
+        ) else {
+            return err!(ErrorCode::InvalidAsset);
+        };
+
+        require_keys_eq!(asset.key(), asset_key, ErrorCode::InvalidAsset);
+        require_keys_eq!(
+            collection.key(),
+            collection_authority.collection,
+            ErrorCode::InvalidCollection
+        );
+        require!(
+            collection_authority.campaign_id == campaign_id,
+            ErrorCode::InvalidCollection
+        );
+
+        // Transferable assets of non-revocable collections are left with their holders, and
+        // assets that were already burned, e.g. by forging, have nothing left to revoke.
+        if !(collection_authority.revocable || collection_authority.soulbound) {
+            return Ok(false);
+        }
+        if BaseAssetV1::try_from(&asset.to_account_info()).is_err() {
+            return Ok(true);
+        }
+
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            COLLECTION_SEED,
+            &collection.key().to_bytes(),
+            &[collection_authority.bump],
+        ]];
+
+        BurnV1CpiBuilder::new(&self.core_program.to_account_info())
+            .asset(&asset.to_account_info())
+            .collection(Some(&collection.to_account_info()))
+            .payer(&self.game_authority.to_account_info())
+            .authority(Some(&collection_authority.to_account_info()))
+            .system_program(Some(&self.system_program.to_account_info()))
+            .invoke_signed(signer_seeds)?;
+
+        Ok(true)
+    }
+}
+
+pub(crate) fn handler(ctx: Context<RevokeCompletion>, campaign_id: u8, bug_id: u8) -> Result<()> {
+    ctx.accounts.revoke_completion(campaign_id, bug_id)?;
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, Campaign, ALL_ATTRIBUTES, ATTRIBUTE_BUG_ID, CAMPAIGN_SEED};
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u8)]
+pub struct SetAttributeSchema<'info> {
+    pub game_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, campaign_id.to_le_bytes().as_ref()],
+        bump,
+        has_one = game_authority
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+}
+
+impl<'info> SetAttributeSchema<'info> {
+    pub fn set_attribute_schema(&mut self, attribute_schema: u16) -> Result<()> {
+        require!(
+            attribute_schema & !ALL_ATTRIBUTES == 0,
+            ErrorCode::InvalidAttributeSchema
+        );
+        // Forging identifies ingredients by their Bug ID attribute.
+        require!(
+            attribute_schema & ATTRIBUTE_BUG_ID != 0,
+            ErrorCode::InvalidAttributeSchema
+        );
+
+        self.campaign.attribute_schema = attribute_schema;
+
+        Ok(())
+    }
+}
+
+pub(crate) fn handler(
+    ctx: Context<SetAttributeSchema>,
+    _campaign_id: u8,
+    attribute_schema: u16,
+) -> Result<()> {
+    ctx.accounts.set_attribute_schema(attribute_schema)?;
+
+    Ok(())
+}
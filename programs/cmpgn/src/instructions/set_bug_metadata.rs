@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ErrorCode, BugMetadata, CollectionAuthority, RarityTiers, BUG_METADATA_SEED,
+    COLLECTION_SEED, MAX_BUG_ID, MAX_NFT_URI_LEN,
+};
+
+#[derive(Accounts)]
+#[instruction(bug_id: u8)]
+pub struct SetBugMetadata<'info> {
+    #[account(
+        mut,
+        constraint = collection_authority.creator == creator.key() @ ErrorCode::NotAuthorized
+    )]
+    pub creator: Signer<'info>,
+
+    /// CHECK: Only used to derive the collection authority and bug metadata PDAs
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [COLLECTION_SEED, collection.key().as_ref()],
+        bump = collection_authority.bump,
+    )]
+    pub collection_authority: Account<'info, CollectionAuthority>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = BugMetadata::DISCRIMINATOR.len() + BugMetadata::INIT_SPACE,
+        seeds = [BUG_METADATA_SEED, collection.key().as_ref(), bug_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub bug_metadata: Account<'info, BugMetadata>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetBugMetadata<'info> {
+    pub fn set_bug_metadata(
+        &mut self,
+        bug_id: u8,
+        uri: String,
+        rarity_tiers: Option<RarityTiers>,
+        bumps: &SetBugMetadataBumps,
+    ) -> Result<()> {
+        require!((1..=MAX_BUG_ID).contains(&bug_id), ErrorCode::InvalidBugId);
+        require!(uri.len() <= MAX_NFT_URI_LEN, ErrorCode::NftUriTooLong);
+
+        if let Some(rarity_tiers) = &rarity_tiers {
+            require!(
+                rarity_tiers.gold_max_duration >= 0
+                    && rarity_tiers.gold_max_duration <= rarity_tiers.silver_max_duration,
+                ErrorCode::InvalidRarityTiers
+            );
+            require!(
+                rarity_tiers.gold_uri.len() <= MAX_NFT_URI_LEN
+                    && rarity_tiers.silver_uri.len() <= MAX_NFT_URI_LEN
+                    && rarity_tiers.bronze_uri.len() <= MAX_NFT_URI_LEN,
+                ErrorCode::NftUriTooLong
+            );
+        }
+
+        self.bug_metadata.set_inner(BugMetadata {
+            collection: self.collection.key(),
+            bug_id,
+            uri,
+            rarity_tiers,
+            bump: bumps.bug_metadata,
+        });
+
+        Ok(())
+    }
+}
+
+pub(crate) fn handler(
+    ctx: Context<SetBugMetadata>,
+    bug_id: u8,
+    uri: String,
+    rarity_tiers: Option<RarityTiers>,
+) -> Result<()> {
+    ctx.accounts
+        .set_bug_metadata(bug_id, uri, rarity_tiers, &ctx.bumps)?;
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, Campaign, CAMPAIGN_SEED, MAX_SPONSORS_PER_CAMPAIGN};
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u8)]
+pub struct SetSponsors<'info> {
+    pub game_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, campaign_id.to_le_bytes().as_ref()],
+        bump,
+        has_one = game_authority
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+}
+
+impl<'info> SetSponsors<'info> {
+    pub fn set_sponsors(&mut self, sponsors: Vec<Pubkey>) -> Result<()> {
+        require!(
+            sponsors.len() <= MAX_SPONSORS_PER_CAMPAIGN,
+            ErrorCode::TooManySponsors
+        );
+
+        self.campaign.sponsors = sponsors;
+
+        Ok(())
+    }
+}
+
+pub(crate) fn handler(
+    ctx: Context<SetSponsors>,
+    _campaign_id: u8,
+    sponsors: Vec<Pubkey>,
+) -> Result<()> {
+    ctx.accounts.set_sponsors(sponsors)?;
+
+    Ok(())
+}
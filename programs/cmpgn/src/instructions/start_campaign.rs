@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::error::ErrorCode;
-use crate::{Campaign, CampaignCompletion};
+use crate::{Campaign, CampaignCompletion, CAMPAIGN_SEED, COMPLETION_SEED, MAX_BUG_ID};
 
 #[derive(Accounts)]
 #[instruction(campaign_id: u8, bug_id: u8)]
@@ -9,19 +9,23 @@ pub struct StartCampaign<'info> {
     #[account(mut)]
     pub player: Signer<'info>,
 
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
     #[account(
-        init,
-        payer = player,
+        init_if_needed,
+        payer = payer.as_ref().map_or_else(|| player.to_account_info(), |payer| payer.to_account_info()),
         space = CampaignCompletion::DISCRIMINATOR.len() + CampaignCompletion::INIT_SPACE,
-        seeds = [b"completion", campaign_id.to_le_bytes().as_ref(), player.key().as_ref(), bug_id.to_le_bytes().as_ref()],
+        seeds = [COMPLETION_SEED, campaign_id.to_le_bytes().as_ref(), player.key().as_ref(), bug_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub campaign_completion: Account<'info, CampaignCompletion>,
 
     #[account(
         mut,
-        seeds = [b"campaign", campaign_id.to_le_bytes().as_ref()],
+        seeds = [CAMPAIGN_SEED, campaign_id.to_le_bytes().as_ref()],
         bump,
+        constraint = payer.as_ref().is_none_or(|payer| payer.key() == player.key() || campaign.sponsors.contains(&payer.key())) @ ErrorCode::UnauthorizedPayer,
     )]
     pub campaign: Box<Account<'info, Campaign>>,
     pub system_program: Program<'info, System>,
@@ -34,18 +38,34 @@ impl<'info> StartCampaign<'info> {
         bug_id: u8,
         bumps: &StartCampaignBumps,
     ) -> Result<()> {
-        require!(bug_id >= 1 && bug_id <= 20, ErrorCode::InvalidBugId);
+        require!((1..=MAX_BUG_ID).contains(&bug_id), ErrorCode::InvalidBugId);
 
         let now = Clock::get()?.unix_timestamp;
 
+        if self.campaign_completion.player != Pubkey::default() {
+            require!(
+                self.campaign_completion.campaign_end.is_none(),
+                ErrorCode::CampaignAlreadyCompleted
+            );
+
+            self.campaign_completion.attempts += 1;
+            return Ok(());
+        }
+
         self.campaign_completion.set_inner(CampaignCompletion {
             player: self.player.key(),
             campaign_id,
             campaign_start: Some(now),
             campaign_end: None,
+            attempts: 1,
             timestamp: None,
             bug_id,
             nft_mint_address: None,
+            daily_solve: false,
+            score: 0,
+            solver_number: 0,
+            revoked: false,
+            reward_claimed: false,
             bump: bumps.campaign_completion,
         });
 
@@ -53,7 +73,7 @@ impl<'info> StartCampaign<'info> {
     }
 }
 
-pub fn handler(ctx: Context<StartCampaign>, campaign_id: u8, bug_id: u8) -> Result<()> {
+pub(crate) fn handler(ctx: Context<StartCampaign>, campaign_id: u8, bug_id: u8) -> Result<()> {
     ctx.accounts
         .start_campaign(campaign_id, bug_id, &ctx.bumps)?;
 
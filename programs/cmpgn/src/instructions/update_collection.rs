@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use mpl_core::{instructions::UpdateCollectionV1CpiBuilder, ID as CORE_PROGRAM_ID};
+
+use crate::{error::ErrorCode, state::CollectionAuthority, COLLECTION_SEED};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateCollectionArgs {
+    pub new_name: Option<String>,
+    pub new_uri: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCollection<'info> {
+    #[account(
+        mut,
+        constraint = collection_authority.creator == creator.key() @ ErrorCode::NotAuthorized
+    )]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = collection.owner == &CORE_PROGRAM_ID @ ErrorCode::InvalidCollection,
+        constraint = collection.key() == collection_authority.collection @ ErrorCode::InvalidCollection
+    )]
+    /// CHECK: This will also be checked by core
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [COLLECTION_SEED, collection.key().as_ref()],
+        bump = collection_authority.bump,
+    )]
+    pub collection_authority: Box<Account<'info, CollectionAuthority>>,
+
+    #[account(address = CORE_PROGRAM_ID)]
+    /// CHECK: This will also be checked by core
+    pub core_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateCollection<'info> {
+    pub fn update_collection(&mut self, args: UpdateCollectionArgs) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            COLLECTION_SEED,
+            &self.collection.key().to_bytes(),
+            &[self.collection_authority.bump],
+        ]];
+
+        let core_program = self.core_program.to_account_info();
+        let collection = self.collection.to_account_info();
+        let payer = self.creator.to_account_info();
+        let authority = self.collection_authority.to_account_info();
+        let system_program = self.system_program.to_account_info();
+
+        let mut builder = UpdateCollectionV1CpiBuilder::new(&core_program);
+        builder
+            .collection(&collection)
+            .payer(&payer)
+            .authority(Some(&authority))
+            .system_program(&system_program);
+
+        if let Some(new_name) = args.new_name {
+            builder.new_name(new_name);
+        }
+        if let Some(new_uri) = args.new_uri {
+            builder.new_uri(new_uri);
+        }
+
+        builder.invoke_signed(signer_seeds)?;
+
+        Ok(())
+    }
+}
+
+pub(crate) fn handler(ctx: Context<UpdateCollection>, args: UpdateCollectionArgs) -> Result<()> {
+    ctx.accounts.update_collection(args)?;
+
+    Ok(())
+}
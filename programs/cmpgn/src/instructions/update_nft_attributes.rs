@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    accounts::BaseAssetV1,
+    fetch_plugin,
+    instructions::UpdatePluginV1CpiBuilder,
+    types::{Attribute, Attributes, Plugin, PluginType},
+    ID as CORE_PROGRAM_ID,
+};
+
+use crate::{error::ErrorCode, state::CollectionAuthority, COLLECTION_SEED};
+
+// Forging matches ingredients on these, so they stay as minted.
+const PROTECTED_ATTRIBUTES: [&str; 2] = ["Bug ID", "Minter"];
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct NftAttribute {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Accounts)]
+pub struct UpdateNftAttributes<'info> {
+    #[account(
+        mut,
+        constraint = collection_authority.creator == creator.key() @ ErrorCode::NotAuthorized
+    )]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = asset.owner == &CORE_PROGRAM_ID @ ErrorCode::InvalidAsset,
+    )]
+    /// CHECK: This will also be checked by core
+    pub asset: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = collection.owner == &CORE_PROGRAM_ID @ ErrorCode::InvalidCollection,
+        constraint = collection.key() == collection_authority.collection @ ErrorCode::InvalidCollection
+    )]
+    /// CHECK: This will also be checked by core
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [COLLECTION_SEED, collection.key().as_ref()],
+        bump = collection_authority.bump,
+    )]
+    pub collection_authority: Box<Account<'info, CollectionAuthority>>,
+
+    #[account(address = CORE_PROGRAM_ID)]
+    /// CHECK: This will also be checked by core
+    pub core_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateNftAttributes<'info> {
+    pub fn update_nft_attributes(&mut self, attributes: Vec<NftAttribute>) -> Result<()> {
+        require!(
+            attributes
+                .iter()
+                .all(|attribute| !PROTECTED_ATTRIBUTES.contains(&attribute.key.as_str())),
+            ErrorCode::ProtectedAttribute
+        );
+
+        let (_, mut current, _) = fetch_plugin::<BaseAssetV1, Attributes>(
+            &self.asset.to_account_info(),
+            PluginType::Attributes,
+        )
+        .map_err(|_| ErrorCode::InvalidAsset)?;
+
+        for attribute in attributes {
+            match current
+                .attribute_list
+                .iter_mut()
+                .find(|existing| existing.key == attribute.key)
+            {
+                Some(existing) => existing.value = attribute.value,
+                None => current.attribute_list.push(Attribute {
+                    key: attribute.key,
+                    value: attribute.value,
+                }),
+            }
+        }
+
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            COLLECTION_SEED,
+            &self.collection.key().to_bytes(),
+            &[self.collection_authority.bump],
+        ]];
+
+        UpdatePluginV1CpiBuilder::new(&self.core_program.to_account_info())
+            .asset(&self.asset.to_account_info())
+            .collection(Some(&self.collection.to_account_info()))
+            .payer(&self.creator.to_account_info())
+            .authority(Some(&self.collection_authority.to_account_info()))
+            .system_program(&self.system_program.to_account_info())
+            .plugin(Plugin::Attributes(current))
+            .invoke_signed(signer_seeds)?;
+
+        Ok(())
+    }
+}
+
+pub(crate) fn handler(
+    ctx: Context<UpdateNftAttributes>,
+    attributes: Vec<NftAttribute>,
+) -> Result<()> {
+    ctx.accounts.update_nft_attributes(attributes)?;
+
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{error::ErrorCode, Campaign, Treasury, CAMPAIGN_SEED, TREASURY_SEED};
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u8)]
+pub struct WithdrawTreasury<'info> {
+    #[account(mut)]
+    pub game_authority: Signer<'info>,
+
+    #[account(
+        seeds = [CAMPAIGN_SEED, campaign_id.to_le_bytes().as_ref()],
+        bump,
+        has_one = game_authority
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, campaign_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    #[account(mut)]
+    pub treasury_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub payment_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawTreasury<'info> {
+    pub fn withdraw_treasury(
+        &mut self,
+        campaign_id: u8,
+        amount: u64,
+        payout_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(
+            payout_accounts.len() == self.treasury.recipients.len(),
+            ErrorCode::InvalidPayoutAccounts
+        );
+
+        let shares = self.split(amount);
+
+        match self.campaign.payment_mint {
+            None => {
+                let rent_exempt_minimum =
+                    Rent::get()?.minimum_balance(self.treasury.to_account_info().data_len());
+                let available = self
+                    .treasury
+                    .get_lamports()
+                    .saturating_sub(rent_exempt_minimum);
+                require!(amount <= available, ErrorCode::InsufficientTreasuryFunds);
+
+                for ((recipient, share), payout_account) in self
+                    .treasury
+                    .recipients
+                    .iter()
+                    .zip(shares.iter())
+                    .zip(payout_accounts.iter())
+                {
+                    require_keys_eq!(
+                        payout_account.key(),
+                        recipient.address,
+                        ErrorCode::InvalidPayoutAccounts
+                    );
+
+                    self.treasury.sub_lamports(*share)?;
+                    payout_account.add_lamports(*share)?;
+                }
+            }
+            Some(payment_mint_key) => {
+                let (Some(treasury_token_account), Some(payment_mint), Some(token_program)) = (
+                    self.treasury_token_account.as_ref(),
+                    self.payment_mint.as_ref(),
+                    self.token_program.as_ref(),
+                ) else {
+                    return err!(ErrorCode::InvalidPayoutAccounts);
+                };
+
+                require_keys_eq!(
+                    payment_mint.key(),
+                    payment_mint_key,
+                    ErrorCode::InvalidPayoutAccounts
+                );
+                require_keys_eq!(
+                    treasury_token_account.owner,
+                    self.treasury.key(),
+                    ErrorCode::InvalidPayoutAccounts
+                );
+                require_keys_eq!(
+                    treasury_token_account.mint,
+                    payment_mint_key,
+                    ErrorCode::InvalidPayoutAccounts
+                );
+                require!(
+                    amount <= treasury_token_account.amount,
+                    ErrorCode::InsufficientTreasuryFunds
+                );
+
+                let signer_seeds: &[&[&[u8]]] = &[&[
+                    TREASURY_SEED,
+                    &campaign_id.to_le_bytes(),
+                    &[self.treasury.bump],
+                ]];
+
+                for ((recipient, share), payout_account) in self
+                    .treasury
+                    .recipients
+                    .iter()
+                    .zip(shares.iter())
+                    .zip(payout_accounts.iter())
+                {
+                    let destination = InterfaceAccount::<TokenAccount>::try_from(payout_account)?;
+                    require_keys_eq!(
+                        destination.owner,
+                        recipient.address,
+                        ErrorCode::InvalidPayoutAccounts
+                    );
+                    require_keys_eq!(
+                        destination.mint,
+                        payment_mint_key,
+                        ErrorCode::InvalidPayoutAccounts
+                    );
+
+                    transfer_checked(
+                        CpiContext::new_with_signer(
+                            token_program.to_account_info(),
+                            TransferChecked {
+                                from: treasury_token_account.to_account_info(),
+                                mint: payment_mint.to_account_info(),
+                                to: payout_account.clone(),
+                                authority: self.treasury.to_account_info(),
+                            },
+                            signer_seeds,
+                        ),
+                        *share,
+                        payment_mint.decimals,
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn split(&self, amount: u64) -> Vec<u64> {
+        let recipients = &self.treasury.recipients;
+        let mut remaining = amount;
+
+        recipients
+            .iter()
+            .enumerate()
+            .map(|(index, recipient)| {
+                // The last recipient absorbs rounding dust so the full amount is paid out.
+                let share = if index == recipients.len() - 1 {
+                    remaining
+                } else {
+                    (amount as u128 * recipient.percentage as u128 / 100) as u64
+                };
+                remaining -= share;
+                share
+            })
+            .collect()
+    }
+}
+
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawTreasury<'info>>,
+    campaign_id: u8,
+    amount: u64,
+) -> Result<()> {
+    ctx.accounts
+        .withdraw_treasury(campaign_id, amount, ctx.remaining_accounts)?;
+
+    Ok(())
+}
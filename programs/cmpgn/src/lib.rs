@@ -1,9 +1,13 @@
+pub mod constants;
+#[cfg(feature = "cpi")]
+pub mod cpi_helpers;
 pub mod error;
 pub mod instructions;
 pub mod state;
 
 use anchor_lang::prelude::*;
 
+pub use constants::*;
 pub use instructions::*;
 pub use state::*;
 
@@ -17,6 +21,46 @@ pub mod cmpgn {
         instructions::initialize_campaign::handler(ctx, campaign_id)
     }
 
+    pub fn configure_treasury(
+        ctx: Context<ConfigureTreasury>,
+        campaign_id: u8,
+        args: ConfigureTreasuryArgs,
+    ) -> Result<()> {
+        instructions::configure_treasury::handler(ctx, campaign_id, args)
+    }
+
+    pub fn withdraw_treasury<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawTreasury<'info>>,
+        campaign_id: u8,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_treasury::handler(ctx, campaign_id, amount)
+    }
+
+    pub fn configure_rewards(
+        ctx: Context<ConfigureRewards>,
+        campaign_id: u8,
+        args: ConfigureRewardsArgs,
+    ) -> Result<()> {
+        instructions::configure_rewards::handler(ctx, campaign_id, args)
+    }
+
+    pub fn set_sponsors(
+        ctx: Context<SetSponsors>,
+        campaign_id: u8,
+        sponsors: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_sponsors::handler(ctx, campaign_id, sponsors)
+    }
+
+    pub fn set_attribute_schema(
+        ctx: Context<SetAttributeSchema>,
+        campaign_id: u8,
+        attribute_schema: u16,
+    ) -> Result<()> {
+        instructions::set_attribute_schema::handler(ctx, campaign_id, attribute_schema)
+    }
+
     pub fn create_collection(
         ctx: Context<CreateCollection>,
         campaign_id: u8,
@@ -25,6 +69,36 @@ pub mod cmpgn {
         instructions::create_collection::handler(ctx, campaign_id, args)
     }
 
+    pub fn update_collection(
+        ctx: Context<UpdateCollection>,
+        args: UpdateCollectionArgs,
+    ) -> Result<()> {
+        instructions::update_collection::handler(ctx, args)
+    }
+
+    pub fn add_collection_plugin(
+        ctx: Context<AddCollectionPlugin>,
+        plugin: CollectionPluginArgs,
+    ) -> Result<()> {
+        instructions::add_collection_plugin::handler(ctx, plugin)
+    }
+
+    pub fn remove_collection_plugin(
+        ctx: Context<RemoveCollectionPlugin>,
+        plugin: CollectionPluginKind,
+    ) -> Result<()> {
+        instructions::remove_collection_plugin::handler(ctx, plugin)
+    }
+
+    pub fn set_bug_metadata(
+        ctx: Context<SetBugMetadata>,
+        bug_id: u8,
+        uri: String,
+        rarity_tiers: Option<RarityTiers>,
+    ) -> Result<()> {
+        instructions::set_bug_metadata::handler(ctx, bug_id, uri, rarity_tiers)
+    }
+
     pub fn start_campaign(ctx: Context<StartCampaign>, campaign_id: u8, bug_id: u8) -> Result<()> {
         instructions::start_campaign::handler(ctx, campaign_id, bug_id)
     }
@@ -37,33 +111,125 @@ pub mod cmpgn {
         instructions::record_campaign_completion::handler(ctx, campaign_id, bug_id)
     }
 
-    pub fn mint_nft(
-        ctx: Context<MintNft>,
+    pub fn mint_nft(ctx: Context<MintNft>, campaign_id: u8, bug_id: u8) -> Result<()> {
+        instructions::mint_nft::handler(ctx, campaign_id, bug_id)
+    }
+
+    pub fn complete_and_mint(
+        ctx: Context<CompleteAndMint>,
+        campaign_id: u8,
+        bug_id: u8,
+    ) -> Result<()> {
+        instructions::complete_and_mint::handler(ctx, campaign_id, bug_id)
+    }
+
+    pub fn claim_reward(ctx: Context<ClaimReward>, campaign_id: u8, bug_id: u8) -> Result<()> {
+        instructions::claim_reward::handler(ctx, campaign_id, bug_id)
+    }
+
+    pub fn fund_bounty(
+        ctx: Context<FundBounty>,
         campaign_id: u8,
         bug_id: u8,
-        name: String,
-        nft_uri: String,
+        args: FundBountyArgs,
+    ) -> Result<()> {
+        instructions::fund_bounty::handler(ctx, campaign_id, bug_id, args)
+    }
+
+    pub fn claim_bounty(ctx: Context<ClaimBounty>, campaign_id: u8, bug_id: u8) -> Result<()> {
+        instructions::claim_bounty::handler(ctx, campaign_id, bug_id)
+    }
+
+    pub fn reclaim_bounty(ctx: Context<ReclaimBounty>, campaign_id: u8, bug_id: u8) -> Result<()> {
+        instructions::reclaim_bounty::handler(ctx, campaign_id, bug_id)
+    }
+
+    pub fn mint_campaign_master(ctx: Context<MintCampaignMaster>, campaign_id: u8) -> Result<()> {
+        instructions::mint_campaign_master::handler(ctx, campaign_id)
+    }
+
+    pub fn create_forge_recipe(
+        ctx: Context<CreateForgeRecipe>,
+        campaign_id: u8,
+        recipe_id: u8,
+        args: ForgeRecipeArgs,
+    ) -> Result<()> {
+        instructions::create_forge_recipe::handler(ctx, campaign_id, recipe_id, args)
+    }
+
+    pub fn forge_nft<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ForgeNft<'info>>,
+        campaign_id: u8,
+        recipe_id: u8,
     ) -> Result<()> {
-        instructions::mint_nft::handler(ctx, campaign_id, bug_id, name, nft_uri)
+        instructions::forge_nft::handler(ctx, campaign_id, recipe_id)
     }
 
-    pub fn get_player_progress(ctx: Context<GetPlayerProgress>, campaign_id: u8) -> Result<()> {
+    pub fn burn_nft(ctx: Context<BurnNft>) -> Result<()> {
+        instructions::burn_nft::handler(ctx)
+    }
+
+    pub fn update_nft_attributes(
+        ctx: Context<UpdateNftAttributes>,
+        attributes: Vec<NftAttribute>,
+    ) -> Result<()> {
+        instructions::update_nft_attributes::handler(ctx, attributes)
+    }
+
+    pub fn revoke_completion(
+        ctx: Context<RevokeCompletion>,
+        campaign_id: u8,
+        bug_id: u8,
+    ) -> Result<()> {
+        instructions::revoke_completion::handler(ctx, campaign_id, bug_id)
+    }
+
+    pub fn get_player_progress(
+        ctx: Context<GetPlayerProgress>,
+        campaign_id: u8,
+    ) -> Result<PlayerProgressView> {
         instructions::get_player_progress::handler(ctx, campaign_id)
     }
 
+    pub fn get_player_progress_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GetPlayerProgressBatch<'info>>,
+        campaign_ids: Vec<u8>,
+    ) -> Result<Vec<PlayerProgressView>> {
+        instructions::get_player_progress_batch::handler(ctx, campaign_ids)
+    }
+
     pub fn has_completed_bug(
         ctx: Context<HasCompletedBug>,
         campaign_id: u8,
         bug_id: u8,
-    ) -> Result<()> {
+    ) -> Result<CompletedBugView> {
         instructions::has_completed_bug::handler(ctx, campaign_id, bug_id)
     }
 
-    pub fn get_campaign_stats(ctx: Context<GetCampaignStats>, campaign_id: u8) -> Result<()> {
+    pub fn has_completed_bugs(
+        ctx: Context<HasCompletedBugs>,
+        campaign_id: u8,
+        bug_ids: Vec<u8>,
+    ) -> Result<Vec<CompletedBugView>> {
+        instructions::has_completed_bugs::handler(ctx, campaign_id, bug_ids)
+    }
+
+    pub fn check_completion(
+        ctx: Context<CheckCompletion>,
+        campaign_id: u8,
+        bug_id: u8,
+    ) -> Result<CompletionStatus> {
+        instructions::check_completion::handler(ctx, campaign_id, bug_id)
+    }
+
+    pub fn get_campaign_stats(
+        ctx: Context<GetCampaignStats>,
+        campaign_id: u8,
+    ) -> Result<CampaignStatsView> {
         instructions::get_campaign_stats::handler(ctx, campaign_id)
     }
 
-    pub fn get_daily_bug(ctx: Context<GetDailyBug>) -> Result<()> {
+    pub fn get_daily_bug(ctx: Context<GetDailyBug>) -> Result<u8> {
         instructions::get_daily_bug::handler(ctx)
     }
 }
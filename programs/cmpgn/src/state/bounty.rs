@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Bounty {
+    pub sponsor: Pubkey,
+    pub campaign_id: u8,
+    pub bug_id: u8,
+    pub nonce: u32,
+    pub payment_mint: Option<Pubkey>,
+    pub amount_per_solver: u64,
+    pub first_solver: u32,
+    pub max_solvers: u32,
+    pub claimed: u32,
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl Bounty {
+    pub fn is_eligible(&self, solver_number: u32) -> bool {
+        solver_number >= self.first_solver && solver_number - self.first_solver < self.max_solvers
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BountyClaim {
+    pub bounty: Pubkey,
+    pub player: Pubkey,
+    pub bump: u8,
+}
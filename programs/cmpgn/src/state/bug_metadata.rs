@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::MAX_NFT_URI_LEN;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RarityTier {
+    Gold,
+    Silver,
+    Bronze,
+}
+
+impl RarityTier {
+    pub fn name(&self) -> &'static str {
+        match self {
+            RarityTier::Gold => "Gold",
+            RarityTier::Silver => "Silver",
+            RarityTier::Bronze => "Bronze",
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct RarityTiers {
+    pub gold_max_duration: i64,
+    pub silver_max_duration: i64,
+    #[max_len(MAX_NFT_URI_LEN)]
+    pub gold_uri: String,
+    #[max_len(MAX_NFT_URI_LEN)]
+    pub silver_uri: String,
+    #[max_len(MAX_NFT_URI_LEN)]
+    pub bronze_uri: String,
+}
+
+impl RarityTiers {
+    pub fn tier_for(&self, solve_duration: i64) -> RarityTier {
+        if solve_duration <= self.gold_max_duration {
+            RarityTier::Gold
+        } else if solve_duration <= self.silver_max_duration {
+            RarityTier::Silver
+        } else {
+            RarityTier::Bronze
+        }
+    }
+
+    pub fn uri_for(&self, tier: RarityTier) -> &str {
+        match tier {
+            RarityTier::Gold => &self.gold_uri,
+            RarityTier::Silver => &self.silver_uri,
+            RarityTier::Bronze => &self.bronze_uri,
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BugMetadata {
+    pub collection: Pubkey,
+    pub bug_id: u8,
+    #[max_len(MAX_NFT_URI_LEN)]
+    pub uri: String,
+    pub rarity_tiers: Option<RarityTiers>,
+    pub bump: u8,
+}
@@ -1,10 +1,20 @@
 use anchor_lang::prelude::*;
 
+use crate::{MAX_BUG_ID, MAX_COLLECTIONS_PER_CAMPAIGN, MAX_SPONSORS_PER_CAMPAIGN};
+
 #[account]
 #[derive(InitSpace)]
 pub struct Campaign {
     pub game_authority: Pubkey,
     pub campaign_id: u8,
     pub total_completions: u8,
+    pub bug_solvers: [u32; MAX_BUG_ID as usize],
+    #[max_len(MAX_COLLECTIONS_PER_CAMPAIGN)]
+    pub collections: Vec<Pubkey>,
+    pub mint_price: u64,
+    pub payment_mint: Option<Pubkey>,
+    #[max_len(MAX_SPONSORS_PER_CAMPAIGN)]
+    pub sponsors: Vec<Pubkey>,
+    pub attribute_schema: u16,
     pub bump: u8,
 }
@@ -7,8 +7,14 @@ pub struct CampaignCompletion {
     pub campaign_id: u8,
     pub campaign_start: Option<i64>,
     pub campaign_end: Option<i64>,
+    pub attempts: u32,
     pub timestamp: Option<i64>,
     pub bug_id: u8,
     pub nft_mint_address: Option<Pubkey>,
+    pub daily_solve: bool,
+    pub score: u64,
+    pub solver_number: u32,
+    pub revoked: bool,
+    pub reward_claimed: bool,
     pub bump: u8,
 }
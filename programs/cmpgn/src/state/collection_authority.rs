@@ -1,13 +1,34 @@
 use anchor_lang::prelude::*;
 
+use crate::{BUG_ID_PLACEHOLDER, MAX_NFT_NAME_LEN, MAX_NFT_URI_LEN};
+
 #[account]
 #[derive(InitSpace)]
 pub struct CollectionAuthority {
     pub creator: Pubkey,
     pub collection: Pubkey,
-    #[max_len(32)]
+    pub campaign_id: u8,
+    #[max_len(MAX_NFT_NAME_LEN)]
     pub nft_name: String,
-    #[max_len(32)]
+    #[max_len(MAX_NFT_URI_LEN)]
     pub nft_uri: String,
+    #[max_len(MAX_NFT_NAME_LEN)]
+    pub master_nft_name: String,
+    #[max_len(MAX_NFT_URI_LEN)]
+    pub master_nft_uri: String,
+    pub soulbound: bool,
+    pub revocable: bool,
     pub bump: u8,
 }
+
+impl CollectionAuthority {
+    pub fn nft_name_for(&self, bug_id: u8) -> String {
+        self.nft_name
+            .replace(BUG_ID_PLACEHOLDER, &bug_id.to_string())
+    }
+
+    pub fn nft_uri_for(&self, bug_id: u8) -> String {
+        self.nft_uri
+            .replace(BUG_ID_PLACEHOLDER, &bug_id.to_string())
+    }
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::MAX_BUG_ID;
+
 #[account]
 #[derive(InitSpace)]
 pub struct DailyBug {
@@ -8,3 +10,10 @@ pub struct DailyBug {
     pub seed: [u8; 32],
     pub requested_at: i64,
 }
+
+impl DailyBug {
+    pub fn bug_id_at(unix_timestamp: i64) -> u8 {
+        let day = unix_timestamp / 86400;
+        ((day % MAX_BUG_ID as i64) + 1) as u8
+    }
+}
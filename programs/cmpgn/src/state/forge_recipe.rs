@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::{MAX_BUG_ID, MAX_NFT_NAME_LEN, MAX_NFT_URI_LEN};
+
+#[account]
+#[derive(InitSpace)]
+pub struct ForgeRecipe {
+    pub campaign_id: u8,
+    pub recipe_id: u8,
+    pub collection: Pubkey,
+    #[max_len(MAX_BUG_ID)]
+    pub bug_ids: Vec<u8>,
+    #[max_len(MAX_NFT_NAME_LEN)]
+    pub reward_name: String,
+    #[max_len(MAX_NFT_URI_LEN)]
+    pub reward_uri: String,
+    pub bump: u8,
+}
@@ -1,11 +1,21 @@
+pub mod bounty;
+pub mod bug_metadata;
 pub mod campaign;
 pub mod campaign_completion;
 pub mod collection_authority;
 pub mod daily_bug;
+pub mod forge_recipe;
 pub mod player_progress;
+pub mod reward_vault;
+pub mod treasury;
 
+pub use bounty::*;
+pub use bug_metadata::*;
 pub use campaign::*;
 pub use campaign_completion::*;
 pub use collection_authority::*;
 pub use daily_bug::*;
+pub use forge_recipe::*;
 pub use player_progress::*;
+pub use reward_vault::*;
+pub use treasury::*;
@@ -8,5 +8,7 @@ pub struct PlayerProgress {
     #[max_len(20)]
     pub completed_bugs: Vec<u8>,
     pub total_completed_bugs: u8,
+    pub total_score: u64,
+    pub master_mint_address: Option<Pubkey>,
     pub bump: u8,
 }
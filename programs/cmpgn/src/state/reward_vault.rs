@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::MAX_BUG_ID;
+
+#[account]
+#[derive(InitSpace)]
+pub struct RewardVault {
+    pub campaign_id: u8,
+    pub reward_mint: Pubkey,
+    pub amounts: [u64; MAX_BUG_ID as usize],
+    pub total_budget: u64,
+    pub total_claimed: u64,
+    pub bump: u8,
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::MAX_TREASURY_RECIPIENTS;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct TreasuryRecipient {
+    pub address: Pubkey,
+    pub percentage: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Treasury {
+    pub campaign_id: u8,
+    #[max_len(MAX_TREASURY_RECIPIENTS)]
+    pub recipients: Vec<TreasuryRecipient>,
+    pub bump: u8,
+}
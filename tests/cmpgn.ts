@@ -18,11 +18,20 @@ describe("cmpgn", () => {
   const unauthorizedUser = Keypair.generate();
   const player = Keypair.generate();
   const asset = Keypair.generate();
+  const recipient = Keypair.generate();
 
   let campaignPda: PublicKey;
   let collectionAuthorityPda: PublicKey;
   let campaignCompletionPda: PublicKey;
   let playerProgressPda: PublicKey;
+  let bugMetadataPda: PublicKey;
+  let treasuryPda: PublicKey;
+
+  const findBugMetadataPda = (collectionKey: PublicKey, bug: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("bug_metadata"), collectionKey.toBuffer(), Buffer.from([bug])],
+      program.programId
+    )[0];
 
   before(async () => {
     const unauthorizedUserBalance = await provider.connection.getBalance(
@@ -81,6 +90,20 @@ describe("cmpgn", () => {
       ],
       program.programId
     )[0];
+
+    treasuryPda = PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury"), Buffer.from([campaignId])],
+      program.programId
+    )[0];
+
+    bugMetadataPda = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("bug_metadata"),
+        collection.publicKey.toBuffer(),
+        Buffer.from([bugId]),
+      ],
+      program.programId
+    )[0];
   });
 
   describe("Initialize Campaign", () => {
@@ -116,8 +139,17 @@ describe("cmpgn", () => {
       const args = {
         name: "Test Collection",
         uri: "https://devnet.irys.xyz/yourhashhere",
-        nftName: "Test NFT",
-        nftUri: "https://gateway.irys.xyz/yourhashhere",
+        nftName: "Test NFT #{bug_id}",
+        nftUri: "https://arweave.net/{bug_id}",
+        masterNftName: "Test Campaign Master",
+        masterNftUri: "https://arweave.net/master",
+        soulbound: false,
+        revocable: true,
+        royalties: {
+          basisPoints: 500,
+          creators: [{ address: gameAuthority.publicKey, percentage: 100 }],
+          ruleSet: { none: {} },
+        },
       };
       try {
         const sig = await program.methods
@@ -153,6 +185,12 @@ describe("cmpgn", () => {
       );
       expect(collectionAuthority.nftName).to.equal(args.nftName);
       expect(collectionAuthority.nftUri).to.equal(args.nftUri);
+      expect(collectionAuthority.campaignId).to.equal(campaignId);
+
+      const campaignAccount = await program.account.campaign.fetch(campaignPda);
+      expect(
+        campaignAccount.collections.map((key: PublicKey) => key.toString())
+      ).to.include(collection.publicKey.toString());
     });
 
     it("fails to create a collection with an unauthorized signer", async () => {
@@ -166,8 +204,13 @@ describe("cmpgn", () => {
       const args = {
         name: "Test Collection",
         uri: "https://devnet.irys.xyz/yourhashhere",
-        nftName: "Test NFT",
-        nftUri: "https://gateway.irys.xyz/yourhashhere",
+        nftName: "Test NFT #{bug_id}",
+        nftUri: "https://arweave.net/{bug_id}",
+        masterNftName: "Test Campaign Master",
+        masterNftUri: "https://arweave.net/master",
+        soulbound: false,
+        revocable: false,
+        royalties: null,
       };
       try {
         const sig = await program.methods
@@ -187,20 +230,108 @@ describe("cmpgn", () => {
         expect(error.error.errorCode.code).to.equal("NotAuthorized");
       }
     });
+
+    it("fails to create a collection with an oversized NFT name template", async () => {
+      const newCollection = Keypair.generate();
+
+      let newCollectionAuthorityPda = PublicKey.findProgramAddressSync(
+        [Buffer.from("collection"), newCollection.publicKey.toBuffer()],
+        program.programId
+      )[0];
+
+      const args = {
+        name: "Test Collection",
+        uri: "https://devnet.irys.xyz/yourhashhere",
+        nftName: "A name template that is far too long #{bug_id}",
+        nftUri: "https://arweave.net/{bug_id}",
+        masterNftName: "Test Campaign Master",
+        masterNftUri: "https://arweave.net/master",
+        soulbound: false,
+        revocable: false,
+        royalties: null,
+      };
+      try {
+        const sig = await program.methods
+          .createCollection(campaignId, args)
+          .accounts({
+            creator: gameAuthority.publicKey,
+            collection: newCollection.publicKey,
+            collectionAuthority: newCollectionAuthorityPda,
+            campaign: campaignPda,
+            coreProgram: MPL_CORE_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([newCollection])
+          .rpc();
+        expect.fail("Should fail with an oversized name template");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("NftNameTooLong");
+      }
+    });
+    it("fails to create a collection with royalty shares that do not add up to 100", async () => {
+      const newCollection = Keypair.generate();
+
+      let newCollectionAuthorityPda = PublicKey.findProgramAddressSync(
+        [Buffer.from("collection"), newCollection.publicKey.toBuffer()],
+        program.programId
+      )[0];
+
+      const args = {
+        name: "Test Collection",
+        uri: "https://devnet.irys.xyz/yourhashhere",
+        nftName: "Test NFT #{bug_id}",
+        nftUri: "https://arweave.net/{bug_id}",
+        masterNftName: "Test Campaign Master",
+        masterNftUri: "https://arweave.net/master",
+        soulbound: false,
+        revocable: false,
+        royalties: {
+          basisPoints: 500,
+          creators: [
+            { address: gameAuthority.publicKey, percentage: 60 },
+            { address: unauthorizedUser.publicKey, percentage: 30 },
+          ],
+          ruleSet: { programDenyList: { 0: [MPL_CORE_PROGRAM_ID] } },
+        },
+      };
+      try {
+        const sig = await program.methods
+          .createCollection(campaignId, args)
+          .accounts({
+            creator: gameAuthority.publicKey,
+            collection: newCollection.publicKey,
+            collectionAuthority: newCollectionAuthorityPda,
+            campaign: campaignPda,
+            coreProgram: MPL_CORE_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([newCollection])
+          .rpc();
+        expect.fail("Should fail with invalid royalty shares");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidRoyaltyShares");
+      }
+    });
   });
 
-  describe("Start Campaign", () => {
-    it("starts a campaign with a valid campaign campaign id and bug id", async () => {
+  describe("Treasury", () => {
+    it("configures the campaign treasury", async () => {
       try {
         const sig = await program.methods
-          .startCampaign(campaignId, bugId)
+          .configureTreasury(campaignId, {
+            mintPrice: new anchor.BN(0),
+            paymentMint: null,
+            recipients: [
+              { address: gameAuthority.publicKey, percentage: 70 },
+              { address: unauthorizedUser.publicKey, percentage: 30 },
+            ],
+          })
           .accounts({
-            player: player.publicKey,
-            campaignCompletion: campaignCompletionPda,
+            gameAuthority: gameAuthority.publicKey,
             campaign: campaignPda,
+            treasury: treasuryPda,
             systemProgram: SystemProgram.programId,
           })
-          .signers([player])
           .rpc();
       } catch (error: any) {
         console.error(`something went wrong: ${error}`);
@@ -210,120 +341,148 @@ describe("cmpgn", () => {
         } else {
           console.log("No logs available in the error.");
         }
+        throw error;
       }
 
-      const campaignCompletion = await program.account.campaignCompletion.fetch(
-        campaignCompletionPda
-      );
-      const campaign = await program.account.campaign.fetch(campaignPda);
-      const now = Math.floor(Date.now() / 1000);
-
-      expect(campaignCompletion.bugId).to.equal(bugId);
-      expect(campaignCompletion.campaignId).to.equal(campaignId);
-      expect(campaignCompletion.campaignId).to.equal(campaign.campaignId);
-      expect(campaignCompletion.campaignStart.toNumber()).to.be.closeTo(
-        now,
-        30
-      );
-      expect(campaignCompletion.campaignEnd).to.be.null;
-      expect(campaignCompletion.timestamp).to.be.null;
-      expect(campaignCompletion.nftMintAddress).to.be.null;
+      const treasury = await program.account.treasury.fetch(treasuryPda);
+      const campaignAccount = await program.account.campaign.fetch(campaignPda);
+      expect(treasury.recipients).to.have.lengthOf(2);
+      expect(campaignAccount.mintPrice.toNumber()).to.equal(0);
+      expect(campaignAccount.paymentMint).to.be.null;
     });
 
-    it("fails to start a campaign with an invalid campaign id", async () => {
-      const invalidCampaignId = 6;
-      const invalidCampaignPda = PublicKey.findProgramAddressSync(
-        [Buffer.from("campaign"), Buffer.from([invalidCampaignId])],
-        program.programId
-      )[0];
-      const invalidCampaignCompletionPda = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("completion"),
-          Buffer.from([invalidCampaignId]),
-          player.publicKey.toBuffer(),
-          Buffer.from([bugId]),
-        ],
-        program.programId
-      )[0];
-
+    it("fails to configure the treasury with shares that do not add up to 100", async () => {
       try {
         const sig = await program.methods
-          .startCampaign(invalidCampaignId, bugId)
+          .configureTreasury(campaignId, {
+            mintPrice: new anchor.BN(1_000_000),
+            paymentMint: null,
+            recipients: [{ address: gameAuthority.publicKey, percentage: 50 }],
+          })
           .accounts({
-            player: player.publicKey,
-            campaignCompletion: invalidCampaignCompletionPda,
-            campaign: invalidCampaignPda,
+            gameAuthority: gameAuthority.publicKey,
+            campaign: campaignPda,
+            treasury: treasuryPda,
             systemProgram: SystemProgram.programId,
           })
-          .signers([player])
           .rpc();
-        expect.fail("Should fail with uninitialized campaign");
+        expect.fail("Should fail with invalid treasury recipients");
       } catch (error: any) {
-        const errorCode = error.error?.errorCode?.code || error.message;
-        expect(errorCode).to.satisfy(
-          (code: string) =>
-            code.includes("AccountNotInitialized") ||
-            code.includes("Account does not exist")
+        expect(error.error.errorCode.code).to.equal(
+          "InvalidTreasuryRecipients"
         );
       }
     });
 
-    it("fails to starts a campaign with an invalid bug id", async () => {
-      const InvalidBugId = 200;
-
-      const invalidCampaignCompletionPda = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("completion"),
-          Buffer.from([campaignId]),
-          player.publicKey.toBuffer(),
-          Buffer.from([InvalidBugId]),
-        ],
-        program.programId
-      )[0];
+    it("fails to withdraw more than the treasury holds", async () => {
       try {
         const sig = await program.methods
-          .startCampaign(campaignId, InvalidBugId)
+          .withdrawTreasury(campaignId, new anchor.BN(1_000_000_000))
           .accounts({
-            player: player.publicKey,
-            campaignCompletion: invalidCampaignCompletionPda,
+            gameAuthority: gameAuthority.publicKey,
             campaign: campaignPda,
+            treasury: treasuryPda,
+            treasuryTokenAccount: null,
+            paymentMint: null,
+            tokenProgram: null,
             systemProgram: SystemProgram.programId,
           })
-          .signers([player])
+          .remainingAccounts([
+            {
+              pubkey: gameAuthority.publicKey,
+              isWritable: true,
+              isSigner: false,
+            },
+            {
+              pubkey: unauthorizedUser.publicKey,
+              isWritable: true,
+              isSigner: false,
+            },
+          ])
           .rpc();
-        expect.fail("Should fail with invalid bug id");
+        expect.fail("Should fail with insufficient treasury funds");
       } catch (error: any) {
-        expect(error.error.errorCode.code).to.equal("InvalidBugId");
+        expect(error.error.errorCode.code).to.equal(
+          "InsufficientTreasuryFunds"
+        );
       }
     });
   });
 
-  describe("Record Campaign Completion", () => {
-    it("records campaign with valid campaign id and bug id", async () => {
-      let existingCompletion;
+  describe("Set Attribute Schema", () => {
+    it("chooses which attributes are written to minted NFTs", async () => {
+      const campaignBefore = await program.account.campaign.fetch(campaignPda);
+      const attributeSchema = campaignBefore.attributeSchema & ~(1 << 4);
+
       try {
-        existingCompletion = await program.account.campaignCompletion.fetch(
-          campaignCompletionPda
-        );
-      } catch {
-        existingCompletion = null;
+        const sig = await program.methods
+          .setAttributeSchema(campaignId, attributeSchema)
+          .accounts({
+            gameAuthority: gameAuthority.publicKey,
+            campaign: campaignPda,
+          })
+          .rpc();
+      } catch (error: any) {
+        console.error(`something went wrong: ${error}`);
+        if (error.logs && Array.isArray(error.logs)) {
+          console.log("Transaction Logs:");
+          error.logs.forEach((log: string) => console.log(log));
+        } else {
+          console.log("No logs available in the error.");
+        }
+        throw error;
       }
 
-      if (existingCompletion?.campaignEnd) {
-        console.log("Campaign already completed.");
-        return;
+      const campaignAccount = await program.account.campaign.fetch(campaignPda);
+      expect(campaignAccount.attributeSchema).to.equal(attributeSchema);
+    });
+
+    it("fails to set an attribute schema with unknown attributes", async () => {
+      try {
+        const sig = await program.methods
+          .setAttributeSchema(campaignId, 1 << 15)
+          .accounts({
+            gameAuthority: gameAuthority.publicKey,
+            campaign: campaignPda,
+          })
+          .rpc();
+        expect.fail("Should fail with an invalid attribute schema");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidAttributeSchema");
       }
+    });
+
+    it("fails to set an attribute schema without the bug ID", async () => {
       try {
         const sig = await program.methods
-          .recordCampaignCompletion(campaignId, bugId)
+          .setAttributeSchema(campaignId, (1 << 15) - 1 - (1 << 5))
           .accounts({
-            player: player.publicKey,
-            campaignCompletion: campaignCompletionPda,
-            playerProgress: playerProgressPda,
+            gameAuthority: gameAuthority.publicKey,
             campaign: campaignPda,
+          })
+          .rpc();
+        expect.fail("Should fail with an invalid attribute schema");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidAttributeSchema");
+      }
+    });
+  });
+
+  describe("Update Collection", () => {
+    it("updates the collection name and uri", async () => {
+      try {
+        const sig = await program.methods
+          .updateCollection({
+            newName: "Renamed Collection",
+            newUri: "https://arweave.net/renamed-collection",
+          })
+          .accounts({
+            creator: gameAuthority.publicKey,
+            collection: collection.publicKey,
+            collectionAuthority: collectionAuthorityPda,
+            coreProgram: MPL_CORE_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
-          .signers([player])
           .rpc();
       } catch (error: any) {
         console.error(`something went wrong: ${error}`);
@@ -333,37 +492,244 @@ describe("cmpgn", () => {
         } else {
           console.log("No logs available in the error.");
         }
+        throw error;
       }
+    });
 
-      const campaignCompletion = await program.account.campaignCompletion.fetch(
-        campaignCompletionPda
-      );
-      const campaign = await program.account.campaign.fetch(campaignPda);
-      const playerProgress = await program.account.playerProgress.fetch(
-        playerProgressPda
-      );
-      const now = Math.floor(Date.now() / 1000);
+    it("adds and removes a collection plugin", async () => {
+      try {
+        await program.methods
+          .addCollectionPlugin({
+            attributes: { 0: [{ key: "Season", value: "1" }] },
+          })
+          .accounts({
+            creator: gameAuthority.publicKey,
+            collection: collection.publicKey,
+            collectionAuthority: collectionAuthorityPda,
+            coreProgram: MPL_CORE_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
 
-      expect(campaignCompletion.bugId).to.equal(bugId);
-      expect(campaignCompletion.campaignId).to.equal(campaignId);
-      expect(campaignCompletion.campaignId).to.equal(campaign.campaignId);
-      expect(campaignCompletion.campaignEnd.toNumber()).to.be.closeTo(now, 60);
-      expect(campaignCompletion.timestamp.toNumber()).to.be.closeTo(now, 60);
-      expect(campaignCompletion.nftMintAddress).to.be.null;
-      expect(playerProgress.player.toString()).to.equal(
-        player.publicKey.toString()
-      );
-      expect(playerProgress.campaignId).to.equal(campaignId);
+        await program.methods
+          .removeCollectionPlugin({ attributes: {} })
+          .accounts({
+            creator: gameAuthority.publicKey,
+            collection: collection.publicKey,
+            collectionAuthority: collectionAuthorityPda,
+            coreProgram: MPL_CORE_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      } catch (error: any) {
+        console.error(`something went wrong: ${error}`);
+        if (error.logs && Array.isArray(error.logs)) {
+          console.log("Transaction Logs:");
+          error.logs.forEach((log: string) => console.log(log));
+        } else {
+          console.log("No logs available in the error.");
+        }
+        throw error;
+      }
     });
 
-    it("fails to record a campaign with an invalid campaign id", async () => {
-      const invalidCampaignId = 200;
+    it("fails to update the collection with an unauthorized signer", async () => {
+      try {
+        const sig = await program.methods
+          .updateCollection({ newName: "Hijacked Collection", newUri: null })
+          .accounts({
+            creator: unauthorizedUser.publicKey,
+            collection: collection.publicKey,
+            collectionAuthority: collectionAuthorityPda,
+            coreProgram: MPL_CORE_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([unauthorizedUser])
+          .rpc();
+        expect.fail("Should fail with unauthorized creator");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("NotAuthorized");
+      }
+    });
+  });
+
+  describe("Set Bug Metadata", () => {
+    it("sets the metadata URI for a bug", async () => {
+      const uri =
+        "https://arweave.net/bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi/1.json";
+      const rarityTiers = {
+        goldMaxDuration: new anchor.BN(3600),
+        silverMaxDuration: new anchor.BN(86400),
+        goldUri: "https://arweave.net/gold/1.json",
+        silverUri: "https://arweave.net/silver/1.json",
+        bronzeUri: "https://arweave.net/bronze/1.json",
+      };
+      try {
+        const sig = await program.methods
+          .setBugMetadata(bugId, uri, rarityTiers)
+          .accounts({
+            creator: gameAuthority.publicKey,
+            collection: collection.publicKey,
+            collectionAuthority: collectionAuthorityPda,
+            bugMetadata: bugMetadataPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      } catch (error: any) {
+        console.error(`something went wrong: ${error}`);
+        if (error.logs && Array.isArray(error.logs)) {
+          console.log("Transaction Logs:");
+          error.logs.forEach((log: string) => console.log(log));
+        } else {
+          console.log("No logs available in the error.");
+        }
+        throw error;
+      }
+
+      const bugMetadata = await program.account.bugMetadata.fetch(
+        bugMetadataPda
+      );
+      expect(bugMetadata.collection.toString()).to.equal(
+        collection.publicKey.toString()
+      );
+      expect(bugMetadata.bugId).to.equal(bugId);
+      expect(bugMetadata.uri).to.equal(uri);
+      expect(bugMetadata.rarityTiers.goldMaxDuration.toNumber()).to.equal(3600);
+      expect(bugMetadata.rarityTiers.goldUri).to.equal(rarityTiers.goldUri);
+    });
+
+    it("fails to set rarity tiers with a gold threshold above the silver threshold", async () => {
+      try {
+        const sig = await program.methods
+          .setBugMetadata(bugId, "https://arweave.net/1.json", {
+            goldMaxDuration: new anchor.BN(86400),
+            silverMaxDuration: new anchor.BN(3600),
+            goldUri: "https://arweave.net/gold/1.json",
+            silverUri: "https://arweave.net/silver/1.json",
+            bronzeUri: "https://arweave.net/bronze/1.json",
+          })
+          .accounts({
+            creator: gameAuthority.publicKey,
+            collection: collection.publicKey,
+            collectionAuthority: collectionAuthorityPda,
+            bugMetadata: bugMetadataPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should fail with invalid rarity tiers");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidRarityTiers");
+      }
+    });
+
+    it("fails to set bug metadata with an unauthorized signer", async () => {
+      try {
+        const sig = await program.methods
+          .setBugMetadata(bugId, "https://arweave.net/unauthorized", null)
+          .accounts({
+            creator: unauthorizedUser.publicKey,
+            collection: collection.publicKey,
+            collectionAuthority: collectionAuthorityPda,
+            bugMetadata: bugMetadataPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([unauthorizedUser])
+          .rpc();
+        expect.fail("Should fail with unauthorized creator");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("NotAuthorized");
+      }
+    });
+  });
+
+  describe("Start Campaign", () => {
+    it("starts a campaign with a valid campaign campaign id and bug id", async () => {
+      try {
+        const sig = await program.methods
+          .startCampaign(campaignId, bugId)
+          .accounts({
+            player: player.publicKey,
+            payer: null,
+            campaignCompletion: campaignCompletionPda,
+            campaign: campaignPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player])
+          .rpc();
+      } catch (error: any) {
+        console.error(`something went wrong: ${error}`);
+        if (error.logs && Array.isArray(error.logs)) {
+          console.log("Transaction Logs:");
+          error.logs.forEach((log: string) => console.log(log));
+        } else {
+          console.log("No logs available in the error.");
+        }
+      }
+
+      const campaignCompletion = await program.account.campaignCompletion.fetch(
+        campaignCompletionPda
+      );
+      const campaign = await program.account.campaign.fetch(campaignPda);
+      const now = Math.floor(Date.now() / 1000);
+
+      expect(campaignCompletion.bugId).to.equal(bugId);
+      expect(campaignCompletion.campaignId).to.equal(campaignId);
+      expect(campaignCompletion.campaignId).to.equal(campaign.campaignId);
+      expect(campaignCompletion.campaignStart.toNumber()).to.be.closeTo(
+        now,
+        30
+      );
+      expect(campaignCompletion.campaignEnd).to.be.null;
+      expect(campaignCompletion.timestamp).to.be.null;
+      expect(campaignCompletion.nftMintAddress).to.be.null;
+      expect(campaignCompletion.attempts).to.equal(1);
+    });
+
+    it("restarts an unfinished campaign and counts the attempt", async () => {
+      const completionBefore = await program.account.campaignCompletion.fetch(
+        campaignCompletionPda
+      );
+
+      try {
+        const sig = await program.methods
+          .startCampaign(campaignId, bugId)
+          .accounts({
+            player: player.publicKey,
+            payer: null,
+            campaignCompletion: campaignCompletionPda,
+            campaign: campaignPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player])
+          .rpc();
+      } catch (error: any) {
+        console.error(`something went wrong: ${error}`);
+        if (error.logs && Array.isArray(error.logs)) {
+          console.log("Transaction Logs:");
+          error.logs.forEach((log: string) => console.log(log));
+        } else {
+          console.log("No logs available in the error.");
+        }
+        throw error;
+      }
+
+      const campaignCompletion = await program.account.campaignCompletion.fetch(
+        campaignCompletionPda
+      );
+
+      expect(campaignCompletion.attempts).to.equal(2);
+      expect(campaignCompletion.campaignStart.toNumber()).to.equal(
+        completionBefore.campaignStart.toNumber()
+      );
+      expect(campaignCompletion.campaignEnd).to.be.null;
+    });
 
+    it("fails to start a campaign with an invalid campaign id", async () => {
+      const invalidCampaignId = 6;
       const invalidCampaignPda = PublicKey.findProgramAddressSync(
         [Buffer.from("campaign"), Buffer.from([invalidCampaignId])],
         program.programId
       )[0];
-
       const invalidCampaignCompletionPda = PublicKey.findProgramAddressSync(
         [
           Buffer.from("completion"),
@@ -374,55 +740,935 @@ describe("cmpgn", () => {
         program.programId
       )[0];
 
-      const invalidPlayerProgressPda = PublicKey.findProgramAddressSync(
+      try {
+        const sig = await program.methods
+          .startCampaign(invalidCampaignId, bugId)
+          .accounts({
+            player: player.publicKey,
+            payer: null,
+            campaignCompletion: invalidCampaignCompletionPda,
+            campaign: invalidCampaignPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player])
+          .rpc();
+        expect.fail("Should fail with uninitialized campaign");
+      } catch (error: any) {
+        const errorCode = error.error?.errorCode?.code || error.message;
+        expect(errorCode).to.satisfy(
+          (code: string) =>
+            code.includes("AccountNotInitialized") ||
+            code.includes("Account does not exist")
+        );
+      }
+    });
+
+    it("fails to starts a campaign with an invalid bug id", async () => {
+      const InvalidBugId = 200;
+
+      const invalidCampaignCompletionPda = PublicKey.findProgramAddressSync(
         [
-          Buffer.from("progress"),
-          Buffer.from([invalidCampaignId]),
+          Buffer.from("completion"),
+          Buffer.from([campaignId]),
           player.publicKey.toBuffer(),
+          Buffer.from([InvalidBugId]),
         ],
         program.programId
       )[0];
+      try {
+        const sig = await program.methods
+          .startCampaign(campaignId, InvalidBugId)
+          .accounts({
+            player: player.publicKey,
+            payer: null,
+            campaignCompletion: invalidCampaignCompletionPda,
+            campaign: campaignPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player])
+          .rpc();
+        expect.fail("Should fail with invalid bug id");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidBugId");
+      }
+    });
+  });
+
+  describe("Record Campaign Completion", () => {
+    it("records campaign with valid campaign id and bug id", async () => {
+      let existingCompletion;
+      try {
+        existingCompletion = await program.account.campaignCompletion.fetch(
+          campaignCompletionPda
+        );
+      } catch {
+        existingCompletion = null;
+      }
+
+      if (existingCompletion?.campaignEnd) {
+        console.log("Campaign already completed.");
+        return;
+      }
+      try {
+        const sig = await program.methods
+          .recordCampaignCompletion(campaignId, bugId)
+          .accounts({
+            player: player.publicKey,
+            payer: null,
+            campaignCompletion: campaignCompletionPda,
+            playerProgress: playerProgressPda,
+            campaign: campaignPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player])
+          .rpc();
+      } catch (error: any) {
+        console.error(`something went wrong: ${error}`);
+        if (error.logs && Array.isArray(error.logs)) {
+          console.log("Transaction Logs:");
+          error.logs.forEach((log: string) => console.log(log));
+        } else {
+          console.log("No logs available in the error.");
+        }
+      }
+
+      const campaignCompletion = await program.account.campaignCompletion.fetch(
+        campaignCompletionPda
+      );
+      const campaign = await program.account.campaign.fetch(campaignPda);
+      const playerProgress = await program.account.playerProgress.fetch(
+        playerProgressPda
+      );
+      const now = Math.floor(Date.now() / 1000);
+
+      expect(campaignCompletion.bugId).to.equal(bugId);
+      expect(campaignCompletion.campaignId).to.equal(campaignId);
+      expect(campaignCompletion.campaignId).to.equal(campaign.campaignId);
+      expect(campaignCompletion.campaignEnd.toNumber()).to.be.closeTo(now, 60);
+      expect(campaignCompletion.timestamp.toNumber()).to.be.closeTo(now, 60);
+      expect(campaignCompletion.nftMintAddress).to.be.null;
+      expect(playerProgress.player.toString()).to.equal(
+        player.publicKey.toString()
+      );
+      expect(playerProgress.campaignId).to.equal(campaignId);
+    });
+
+    it("flags daily solves and applies the score multiplier", async () => {
+      const campaignCompletion = await program.account.campaignCompletion.fetch(
+        campaignCompletionPda
+      );
+      const playerProgress = await program.account.playerProgress.fetch(
+        playerProgressPda
+      );
+      const completionDay = Math.floor(
+        campaignCompletion.campaignEnd.toNumber() / 86400
+      );
+      const dailyBugId = (completionDay % 20) + 1;
+      const expectedScore = dailyBugId === bugId ? 200 : 100;
+
+      expect(campaignCompletion.dailySolve).to.equal(dailyBugId === bugId);
+      expect(campaignCompletion.score.toNumber()).to.equal(expectedScore);
+      expect(playerProgress.totalScore.toNumber()).to.equal(expectedScore);
+    });
+
+    it("assigns the next solver number for the bug", async () => {
+      const campaignCompletion = await program.account.campaignCompletion.fetch(
+        campaignCompletionPda
+      );
+      const campaign = await program.account.campaign.fetch(campaignPda);
+
+      expect(campaignCompletion.solverNumber).to.be.at.least(1);
+      expect(campaign.bugSolvers[bugId - 1]).to.be.at.least(
+        campaignCompletion.solverNumber
+      );
+    });
+
+    it("fails to record a campaign with an invalid campaign id", async () => {
+      const invalidCampaignId = 200;
+
+      const invalidCampaignPda = PublicKey.findProgramAddressSync(
+        [Buffer.from("campaign"), Buffer.from([invalidCampaignId])],
+        program.programId
+      )[0];
+
+      const invalidCampaignCompletionPda = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("completion"),
+          Buffer.from([invalidCampaignId]),
+          player.publicKey.toBuffer(),
+          Buffer.from([bugId]),
+        ],
+        program.programId
+      )[0];
+
+      const invalidPlayerProgressPda = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("progress"),
+          Buffer.from([invalidCampaignId]),
+          player.publicKey.toBuffer(),
+        ],
+        program.programId
+      )[0];
+
+      try {
+        const sig = await program.methods
+          .recordCampaignCompletion(invalidCampaignId, bugId)
+          .accounts({
+            player: player.publicKey,
+            payer: null,
+            campaignCompletion: invalidCampaignCompletionPda,
+            playerProgress: invalidPlayerProgressPda,
+            campaign: invalidCampaignPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player])
+          .rpc();
+
+        expect.fail("Should fail with uninitialized campaign");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.be.oneOf([
+          "AccountNotInitialized",
+          "ConstraintSeeds",
+        ]);
+      }
+    });
+  });
+
+  describe("Mint NFT", () => {
+    it("mints an NFT", async () => {
+      try {
+        const sig = await program.methods
+          .mintNft(campaignId, bugId)
+          .accounts({
+            player: player.publicKey,
+            payer: null,
+            asset: asset.publicKey,
+            collection: collection.publicKey,
+            collectionAuthority: collectionAuthorityPda,
+            campaign: campaignPda,
+            bugMetadata: bugMetadataPda,
+            campaignCompletion: campaignCompletionPda,
+            recipient: recipient.publicKey,
+            treasury: null,
+            playerTokenAccount: null,
+            treasuryTokenAccount: null,
+            paymentMint: null,
+            tokenProgram: null,
+            coreProgram: MPL_CORE_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player, asset])
+          .rpc();
+      } catch (error: any) {
+        console.error(`something went wrong: ${error}`);
+        if (error.logs && Array.isArray(error.logs)) {
+          console.log("Transaction Logs:");
+          error.logs.forEach((log: string) => console.log(log));
+        } else {
+          console.log("No logs available in the error.");
+        }
+        throw error;
+      }
+      const collectionAuthority =
+        await program.account.collectionAuthority.fetch(collectionAuthorityPda);
+      const campaignCompletion = await program.account.campaignCompletion.fetch(
+        campaignCompletionPda
+      );
+
+      expect(collectionAuthority.creator.toString()).to.equal(
+        gameAuthority.publicKey.toString()
+      );
+      expect(campaignCompletion.nftMintAddress.toString()).to.equal(
+        asset.publicKey.toString()
+      );
+
+      const assetAccount = await provider.connection.getAccountInfo(
+        asset.publicKey
+      );
+      expect(new PublicKey(assetAccount.data.subarray(1, 33)).toString()).to.equal(
+        recipient.publicKey.toString()
+      );
+    });
+
+    it("fails to mint an NFT with an invalid collection", async () => {
+      const invalidCollection = Keypair.generate();
+      const invalidAsset = Keypair.generate();
+
+      try {
+        const sig = await program.methods
+          .mintNft(campaignId, bugId)
+          .accounts({
+            player: player.publicKey,
+            payer: null,
+            asset: invalidAsset.publicKey,
+            collection: invalidCollection.publicKey,
+            collectionAuthority: collectionAuthorityPda,
+            campaign: campaignPda,
+            bugMetadata: findBugMetadataPda(invalidCollection.publicKey, bugId),
+            campaignCompletion: campaignCompletionPda,
+            recipient: null,
+            treasury: null,
+            playerTokenAccount: null,
+            treasuryTokenAccount: null,
+            paymentMint: null,
+            tokenProgram: null,
+            coreProgram: MPL_CORE_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player, invalidAsset])
+          .rpc();
+
+        expect.fail("Should fail with invalid collection");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidCollection");
+      }
+    });
+
+    it("fails to mint an NFT without the bug's own metadata account", async () => {
+      const otherAsset = Keypair.generate();
+
+      try {
+        await program.methods
+          .mintNft(campaignId, bugId)
+          .accounts({
+            player: player.publicKey,
+            payer: null,
+            asset: otherAsset.publicKey,
+            collection: collection.publicKey,
+            collectionAuthority: collectionAuthorityPda,
+            campaign: campaignPda,
+            bugMetadata: findBugMetadataPda(collection.publicKey, bugId + 1),
+            campaignCompletion: campaignCompletionPda,
+            recipient: null,
+            treasury: null,
+            playerTokenAccount: null,
+            treasuryTokenAccount: null,
+            paymentMint: null,
+            tokenProgram: null,
+            coreProgram: MPL_CORE_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player, otherAsset])
+          .rpc();
+
+        expect.fail("Should fail with another bug's metadata account");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("ConstraintSeeds");
+      }
+    });
+
+    it("fails to mint an NFT into a collection bound to another campaign", async () => {
+      const otherCampaignId = 2;
+      const otherCollection = Keypair.generate();
+      const otherAsset = Keypair.generate();
+
+      const otherCampaignPda = PublicKey.findProgramAddressSync(
+        [Buffer.from("campaign"), Buffer.from([otherCampaignId])],
+        program.programId
+      )[0];
+      const otherCollectionAuthorityPda = PublicKey.findProgramAddressSync(
+        [Buffer.from("collection"), otherCollection.publicKey.toBuffer()],
+        program.programId
+      )[0];
+
+      await program.methods
+        .initialize(otherCampaignId)
+        .accounts({
+          gameAuthority: gameAuthority.publicKey,
+          campaign: otherCampaignPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await program.methods
+        .createCollection(otherCampaignId, {
+          name: "Other Collection",
+          uri: "https://devnet.irys.xyz/yourhashhere",
+          nftName: "Other NFT #{bug_id}",
+          nftUri: "https://arweave.net/{bug_id}",
+          masterNftName: "Other Campaign Master",
+          masterNftUri: "https://arweave.net/master",
+          soulbound: false,
+          revocable: false,
+          royalties: null,
+        })
+        .accounts({
+          creator: gameAuthority.publicKey,
+          collection: otherCollection.publicKey,
+          collectionAuthority: otherCollectionAuthorityPda,
+          campaign: otherCampaignPda,
+          coreProgram: MPL_CORE_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([otherCollection])
+        .rpc();
+
+      try {
+        const sig = await program.methods
+          .mintNft(campaignId, bugId)
+          .accounts({
+            player: player.publicKey,
+            payer: null,
+            asset: otherAsset.publicKey,
+            collection: otherCollection.publicKey,
+            collectionAuthority: otherCollectionAuthorityPda,
+            campaign: campaignPda,
+            bugMetadata: findBugMetadataPda(otherCollection.publicKey, bugId),
+            campaignCompletion: campaignCompletionPda,
+            recipient: null,
+            treasury: null,
+            playerTokenAccount: null,
+            treasuryTokenAccount: null,
+            paymentMint: null,
+            tokenProgram: null,
+            coreProgram: MPL_CORE_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player, otherAsset])
+          .rpc();
+
+        expect.fail("Should fail with a collection from another campaign");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidCollection");
+      }
+    });
+  });
+
+  describe("Claim Reward", () => {
+    it("fails to claim a reward before the campaign rewards are configured", async () => {
+      const rewardVaultPda = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), Buffer.from([campaignId])],
+        program.programId
+      )[0];
+      const vaultTokenAccountPda = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_tokens"), Buffer.from([campaignId])],
+        program.programId
+      )[0];
+
+      try {
+        const sig = await program.methods
+          .claimReward(campaignId, bugId)
+          .accounts({
+            player: player.publicKey,
+            campaignCompletion: campaignCompletionPda,
+            rewardVault: rewardVaultPda,
+            rewardMint: Keypair.generate().publicKey,
+            vaultTokenAccount: vaultTokenAccountPda,
+            playerTokenAccount: Keypair.generate().publicKey,
+            tokenProgram: new PublicKey(
+              "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            ),
+          })
+          .signers([player])
+          .rpc();
+        expect.fail("Should fail without a configured reward vault");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("AccountNotInitialized");
+      }
+    });
+  });
+
+  describe("Bounties", () => {
+    const bountyNonce = 0;
+    const amountPerSolver = 0.01 * anchor.web3.LAMPORTS_PER_SOL;
+    let bountyPda: PublicKey;
+
+    const findBountyClaimPda = (claimant: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("bounty_claim"), bountyPda.toBuffer(), claimant.toBuffer()],
+        program.programId
+      )[0];
+
+    before(() => {
+      bountyPda = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("bounty"),
+          Buffer.from([campaignId]),
+          Buffer.from([bugId]),
+          gameAuthority.publicKey.toBuffer(),
+          new anchor.BN(bountyNonce).toArrayLike(Buffer, "le", 4),
+        ],
+        program.programId
+      )[0];
+    });
+
+    it("funds a SOL bounty for a bug", async () => {
+      const expiresAt = Math.floor(Date.now() / 1000) + 3600;
+
+      try {
+        const sig = await program.methods
+          .fundBounty(campaignId, bugId, {
+            nonce: bountyNonce,
+            amountPerSolver: new anchor.BN(amountPerSolver),
+            maxSolvers: 2,
+            expiresAt: new anchor.BN(expiresAt),
+          })
+          .accounts({
+            sponsor: gameAuthority.publicKey,
+            campaign: campaignPda,
+            bounty: bountyPda,
+            sponsorTokenAccount: null,
+            escrowTokenAccount: null,
+            paymentMint: null,
+            tokenProgram: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      } catch (error: any) {
+        console.error(`something went wrong: ${error}`);
+        if (error.logs && Array.isArray(error.logs)) {
+          console.log("Transaction Logs:");
+          error.logs.forEach((log: string) => console.log(log));
+        } else {
+          console.log("No logs available in the error.");
+        }
+        throw error;
+      }
+
+      const bounty = await program.account.bounty.fetch(bountyPda);
+      const campaignAccount = await program.account.campaign.fetch(campaignPda);
+
+      expect(bounty.maxSolvers).to.equal(2);
+      expect(bounty.claimed).to.equal(0);
+      expect(bounty.firstSolver).to.equal(
+        campaignAccount.bugSolvers[bugId - 1] + 1
+      );
+    });
+
+    it("fails to claim a bounty for a bug solved before it was funded", async () => {
+      try {
+        const sig = await program.methods
+          .claimBounty(campaignId, bugId)
+          .accounts({
+            player: player.publicKey,
+            campaignCompletion: campaignCompletionPda,
+            bounty: bountyPda,
+            bountyClaim: findBountyClaimPda(player.publicKey),
+            escrowTokenAccount: null,
+            playerTokenAccount: null,
+            paymentMint: null,
+            tokenProgram: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player])
+          .rpc();
+        expect.fail("Should fail with a completion that is not eligible");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("NotEligibleForBounty");
+      }
+    });
+
+    it("claims a SOL bounty for a bug solved after it was funded", async () => {
+      const solver = Keypair.generate();
+      const solverCompletionPda = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("completion"),
+          Buffer.from([campaignId]),
+          solver.publicKey.toBuffer(),
+          Buffer.from([bugId]),
+        ],
+        program.programId
+      )[0];
+      const solverProgressPda = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("progress"),
+          Buffer.from([campaignId]),
+          solver.publicKey.toBuffer(),
+        ],
+        program.programId
+      )[0];
+
+      try {
+        await provider.sendAndConfirm(
+          new anchor.web3.Transaction().add(
+            SystemProgram.transfer({
+              fromPubkey: gameAuthority.publicKey,
+              toPubkey: solver.publicKey,
+              lamports: 0.1 * anchor.web3.LAMPORTS_PER_SOL,
+            })
+          )
+        );
+
+        await program.methods
+          .startCampaign(campaignId, bugId)
+          .accounts({
+            player: solver.publicKey,
+            payer: null,
+            campaignCompletion: solverCompletionPda,
+            campaign: campaignPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([solver])
+          .rpc();
+
+        await program.methods
+          .recordCampaignCompletion(campaignId, bugId)
+          .accounts({
+            player: solver.publicKey,
+            payer: null,
+            campaignCompletion: solverCompletionPda,
+            playerProgress: solverProgressPda,
+            campaign: campaignPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([solver])
+          .rpc();
+      } catch (error: any) {
+        console.error(`something went wrong: ${error}`);
+        if (error.logs && Array.isArray(error.logs)) {
+          console.log("Transaction Logs:");
+          error.logs.forEach((log: string) => console.log(log));
+        } else {
+          console.log("No logs available in the error.");
+        }
+        throw error;
+      }
+
+      const balanceBefore = await provider.connection.getBalance(
+        solver.publicKey
+      );
+
+      try {
+        const sig = await program.methods
+          .claimBounty(campaignId, bugId)
+          .accounts({
+            player: solver.publicKey,
+            campaignCompletion: solverCompletionPda,
+            bounty: bountyPda,
+            bountyClaim: findBountyClaimPda(solver.publicKey),
+            escrowTokenAccount: null,
+            playerTokenAccount: null,
+            paymentMint: null,
+            tokenProgram: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([solver])
+          .rpc();
+      } catch (error: any) {
+        console.error(`something went wrong: ${error}`);
+        if (error.logs && Array.isArray(error.logs)) {
+          console.log("Transaction Logs:");
+          error.logs.forEach((log: string) => console.log(log));
+        } else {
+          console.log("No logs available in the error.");
+        }
+        throw error;
+      }
+
+      const balanceAfter = await provider.connection.getBalance(
+        solver.publicKey
+      );
+      const claimRent =
+        await provider.connection.getMinimumBalanceForRentExemption(
+          program.account.bountyClaim.size
+        );
+      const bounty = await program.account.bounty.fetch(bountyPda);
+
+      // The solver pays rent for the claim record; the transaction fee is paid by the provider.
+      expect(balanceAfter - balanceBefore).to.equal(amountPerSolver - claimRent);
+      expect(bounty.claimed).to.equal(1);
+    });
+
+    it("fails to reclaim a bounty before it expires", async () => {
+      try {
+        const sig = await program.methods
+          .reclaimBounty(campaignId, bugId)
+          .accounts({
+            sponsor: gameAuthority.publicKey,
+            bounty: bountyPda,
+            escrowTokenAccount: null,
+            sponsorTokenAccount: null,
+            paymentMint: null,
+            tokenProgram: null,
+          })
+          .rpc();
+        expect.fail("Should fail before the bounty expires");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("BountyNotExpired");
+      }
+    });
+  });
+
+  describe("Sponsors", () => {
+    it("whitelists a sponsor on the campaign", async () => {
+      try {
+        const sig = await program.methods
+          .setSponsors(campaignId, [gameAuthority.publicKey])
+          .accounts({
+            gameAuthority: gameAuthority.publicKey,
+            campaign: campaignPda,
+          })
+          .rpc();
+      } catch (error: any) {
+        console.error(`something went wrong: ${error}`);
+        if (error.logs && Array.isArray(error.logs)) {
+          console.log("Transaction Logs:");
+          error.logs.forEach((log: string) => console.log(log));
+        } else {
+          console.log("No logs available in the error.");
+        }
+        throw error;
+      }
+
+      const campaignAccount = await program.account.campaign.fetch(campaignPda);
+      expect(
+        campaignAccount.sponsors.map((sponsor) => sponsor.toString())
+      ).to.deep.equal([gameAuthority.publicKey.toString()]);
+    });
+
+    it("fails to start a campaign with a payer that is not a sponsor", async () => {
+      const thirdBugId = 3;
+      const thirdCompletionPda = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("completion"),
+          Buffer.from([campaignId]),
+          player.publicKey.toBuffer(),
+          Buffer.from([thirdBugId]),
+        ],
+        program.programId
+      )[0];
+
+      try {
+        const sig = await program.methods
+          .startCampaign(campaignId, thirdBugId)
+          .accounts({
+            player: player.publicKey,
+            payer: unauthorizedUser.publicKey,
+            campaignCompletion: thirdCompletionPda,
+            campaign: campaignPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player, unauthorizedUser])
+          .rpc();
+        expect.fail("Should fail with an unauthorized payer");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("UnauthorizedPayer");
+      }
+    });
+  });
+
+  describe("Complete And Mint", () => {
+    it("records a completion and mints its NFT in one instruction", async () => {
+      const secondBugId = 2;
+      const secondAsset = Keypair.generate();
+      const secondCompletionPda = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("completion"),
+          Buffer.from([campaignId]),
+          player.publicKey.toBuffer(),
+          Buffer.from([secondBugId]),
+        ],
+        program.programId
+      )[0];
+
+      try {
+        await program.methods
+          .startCampaign(campaignId, secondBugId)
+          .accounts({
+            player: player.publicKey,
+            payer: gameAuthority.publicKey,
+            campaignCompletion: secondCompletionPda,
+            campaign: campaignPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player])
+          .rpc();
+
+        await program.methods
+          .completeAndMint(campaignId, secondBugId)
+          .accounts({
+            player: player.publicKey,
+            payer: gameAuthority.publicKey,
+            asset: secondAsset.publicKey,
+            collection: collection.publicKey,
+            collectionAuthority: collectionAuthorityPda,
+            campaign: campaignPda,
+            bugMetadata: findBugMetadataPda(collection.publicKey, secondBugId),
+            campaignCompletion: secondCompletionPda,
+            playerProgress: playerProgressPda,
+            recipient: null,
+            treasury: null,
+            playerTokenAccount: null,
+            treasuryTokenAccount: null,
+            paymentMint: null,
+            tokenProgram: null,
+            coreProgram: MPL_CORE_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player, secondAsset])
+          .rpc();
+      } catch (error: any) {
+        console.error(`something went wrong: ${error}`);
+        if (error.logs && Array.isArray(error.logs)) {
+          console.log("Transaction Logs:");
+          error.logs.forEach((log: string) => console.log(log));
+        } else {
+          console.log("No logs available in the error.");
+        }
+        throw error;
+      }
+
+      const campaignCompletion = await program.account.campaignCompletion.fetch(
+        secondCompletionPda
+      );
+      const playerProgress = await program.account.playerProgress.fetch(
+        playerProgressPda
+      );
+
+      expect(campaignCompletion.campaignEnd).to.not.be.null;
+      expect(campaignCompletion.nftMintAddress.toString()).to.equal(
+        secondAsset.publicKey.toString()
+      );
+      expect(Array.from(playerProgress.completedBugs)).to.include(secondBugId);
+    });
+  });
+
+  describe("Mint Campaign Master", () => {
+    it("fails to mint the campaign master NFT before every bug is completed", async () => {
+      const masterAsset = Keypair.generate();
+
+      try {
+        const sig = await program.methods
+          .mintCampaignMaster(campaignId)
+          .accounts({
+            player: player.publicKey,
+            asset: masterAsset.publicKey,
+            collection: collection.publicKey,
+            collectionAuthority: collectionAuthorityPda,
+            campaign: campaignPda,
+            playerProgress: playerProgressPda,
+            coreProgram: MPL_CORE_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player, masterAsset])
+          .rpc();
+        expect.fail("Should fail with an incomplete campaign");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal(
+          "CampaignNotFullyCompleted"
+        );
+      }
+    });
+  });
+
+  describe("Update NFT Attributes", () => {
+    it("updates the attributes of a minted NFT", async () => {
+      try {
+        const sig = await program.methods
+          .updateNftAttributes([
+            { key: "Verified", value: "true" },
+            { key: "Final Rank", value: "1" },
+          ])
+          .accounts({
+            creator: gameAuthority.publicKey,
+            asset: asset.publicKey,
+            collection: collection.publicKey,
+            collectionAuthority: collectionAuthorityPda,
+            coreProgram: MPL_CORE_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      } catch (error: any) {
+        console.error(`something went wrong: ${error}`);
+        if (error.logs && Array.isArray(error.logs)) {
+          console.log("Transaction Logs:");
+          error.logs.forEach((log: string) => console.log(log));
+        } else {
+          console.log("No logs available in the error.");
+        }
+        throw error;
+      }
+    });
+
+    it("fails to update NFT attributes with an unauthorized signer", async () => {
+      try {
+        const sig = await program.methods
+          .updateNftAttributes([{ key: "Verified", value: "true" }])
+          .accounts({
+            creator: unauthorizedUser.publicKey,
+            asset: asset.publicKey,
+            collection: collection.publicKey,
+            collectionAuthority: collectionAuthorityPda,
+            coreProgram: MPL_CORE_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([unauthorizedUser])
+          .rpc();
+        expect.fail("Should fail with unauthorized creator");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("NotAuthorized");
+      }
+    });
 
+    it("fails to update the Bug ID attribute of an NFT", async () => {
       try {
-        const sig = await program.methods
-          .recordCampaignCompletion(invalidCampaignId, bugId)
+        await program.methods
+          .updateNftAttributes([{ key: "Bug ID", value: "2" }])
           .accounts({
-            player: player.publicKey,
-            campaignCompletion: invalidCampaignCompletionPda,
-            playerProgress: invalidPlayerProgressPda,
-            campaign: invalidCampaignPda,
+            creator: gameAuthority.publicKey,
+            asset: asset.publicKey,
+            collection: collection.publicKey,
+            collectionAuthority: collectionAuthorityPda,
+            coreProgram: MPL_CORE_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
-          .signers([player])
           .rpc();
-
-        expect.fail("Should fail with uninitialized campaign");
+        expect.fail("Should fail with a protected attribute");
       } catch (error: any) {
-        expect(error.error.errorCode.code).to.be.oneOf([
-          "AccountNotInitialized",
-          "ConstraintSeeds",
-        ]);
+        expect(error.error.errorCode.code).to.equal("ProtectedAttribute");
       }
     });
   });
 
-  describe("Mint NFT", () => {
-    it("mints an NFT", async () => {
-      const nftName = "test";
-      const nftUri = "https://gateway.irys.xyz/yourhashhere";
+  describe("Burn NFT", () => {
+    it("fails to burn an NFT from a collection that is not soulbound", async () => {
       try {
         const sig = await program.methods
-          .mintNft(campaignId, bugId, nftName, nftUri)
+          .burnNft()
           .accounts({
-            player: player.publicKey,
+            creator: gameAuthority.publicKey,
             asset: asset.publicKey,
             collection: collection.publicKey,
             collectionAuthority: collectionAuthorityPda,
-            campaignCompletion: campaignCompletionPda,
             coreProgram: MPL_CORE_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
-          .signers([player, asset])
+          .rpc();
+        expect.fail("Should fail for a collection that is not soulbound");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("CollectionNotSoulbound");
+      }
+    });
+  });
+
+  describe("Forge NFT", () => {
+    const recipeId = 1;
+    let forgeRecipePda: PublicKey;
+
+    before(() => {
+      forgeRecipePda = PublicKey.findProgramAddressSync(
+        [Buffer.from("recipe"), Buffer.from([campaignId]), Buffer.from([recipeId])],
+        program.programId
+      )[0];
+    });
+
+    it("creates a forge recipe", async () => {
+      const args = {
+        bugIds: Buffer.from([bugId]),
+        rewardName: "Forged Bug Hunter",
+        rewardUri: "https://arweave.net/forged",
+      };
+      try {
+        const sig = await program.methods
+          .createForgeRecipe(campaignId, recipeId, args)
+          .accounts({
+            gameAuthority: gameAuthority.publicKey,
+            campaign: campaignPda,
+            collection: collection.publicKey,
+            collectionAuthority: collectionAuthorityPda,
+            forgeRecipe: forgeRecipePda,
+            systemProgram: SystemProgram.programId,
+          })
           .rpc();
       } catch (error: any) {
         console.error(`something went wrong: ${error}`);
@@ -434,44 +1680,37 @@ describe("cmpgn", () => {
         }
         throw error;
       }
-      const collectionAuthority =
-        await program.account.collectionAuthority.fetch(collectionAuthorityPda);
-      const campaignCompletion = await program.account.campaignCompletion.fetch(
-        campaignCompletionPda
-      );
 
-      expect(collectionAuthority.creator.toString()).to.equal(
-        gameAuthority.publicKey.toString()
+      const forgeRecipe = await program.account.forgeRecipe.fetch(
+        forgeRecipePda
       );
-      expect(campaignCompletion.nftMintAddress.toString()).to.equal(
-        asset.publicKey.toString()
+      expect(forgeRecipe.collection.toString()).to.equal(
+        collection.publicKey.toString()
       );
+      expect(Array.from(forgeRecipe.bugIds)).to.deep.equal([bugId]);
+      expect(forgeRecipe.rewardName).to.equal(args.rewardName);
     });
 
-    it("fails to mint an NFT with an invalid collection", async () => {
-      const invalidCollection = Keypair.generate();
-      const invalidAsset = Keypair.generate();
-      const nftName = "test";
-      const nftUri = "https://gateway.irys.xyz/yourhashhere";
+    it("fails to forge without the assets required by the recipe", async () => {
+      const forgedAsset = Keypair.generate();
 
       try {
         const sig = await program.methods
-          .mintNft(campaignId, bugId, nftName, nftUri)
+          .forgeNft(campaignId, recipeId)
           .accounts({
             player: player.publicKey,
-            asset: invalidAsset.publicKey,
-            collection: invalidCollection.publicKey,
+            asset: forgedAsset.publicKey,
+            collection: collection.publicKey,
             collectionAuthority: collectionAuthorityPda,
-            campaignCompletion: campaignCompletionPda,
+            forgeRecipe: forgeRecipePda,
             coreProgram: MPL_CORE_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
-          .signers([player, invalidAsset])
+          .signers([player, forgedAsset])
           .rpc();
-
-        expect.fail("Should fail with invalid collection");
+        expect.fail("Should fail without forge ingredients");
       } catch (error: any) {
-        expect(error.error.errorCode.code).to.equal("InvalidCollection");
+        expect(error.error.errorCode.code).to.equal("InvalidForgeIngredients");
       }
     });
   });
@@ -485,7 +1724,6 @@ describe("cmpgn", () => {
             player: player.publicKey,
             playerProgress: playerProgressPda,
           })
-          .signers([player])
           .rpc({ commitment: "confirmed" });
 
         const tx = await provider.connection.getTransaction(sig, {
@@ -518,35 +1756,75 @@ describe("cmpgn", () => {
       }
     });
 
-    it("fails to get the current player progress for an invalid player", async () => {
-      const invalidPlayer = Keypair.generate();
+    it("returns any player's progress through simulation", async () => {
+      const progress = await program.methods
+        .getPlayerProgress(campaignId)
+        .accounts({
+          player: player.publicKey,
+          playerProgress: playerProgressPda,
+        })
+        .view();
 
-      const invalidPlayerProgressPda = PublicKey.findProgramAddressSync(
+      expect(progress.player.toString()).to.equal(player.publicKey.toString());
+      expect(progress.campaignId).to.equal(campaignId);
+      expect(Array.from(progress.completedBugs)).to.include(bugId);
+    });
+
+    it("gets a player's progress across several campaigns in one call", async () => {
+      const otherCampaignId = 2;
+      const otherProgressPda = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("progress"),
+          Buffer.from([otherCampaignId]),
+          player.publicKey.toBuffer(),
+        ],
+        program.programId
+      )[0];
+
+      const progress = await program.methods
+        .getPlayerProgressBatch(Buffer.from([campaignId, otherCampaignId]))
+        .accounts({
+          player: player.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: playerProgressPda, isWritable: false, isSigner: false },
+          { pubkey: otherProgressPda, isWritable: false, isSigner: false },
+        ])
+        .view();
+
+      expect(progress).to.have.lengthOf(2);
+      expect(Array.from(progress[0].completedBugs)).to.include(bugId);
+      expect(progress[1].campaignId).to.equal(otherCampaignId);
+      expect(progress[1].totalCompletedBugs).to.equal(0);
+    });
+
+    it("returns empty progress for a player with no progress", async () => {
+      const newPlayer = Keypair.generate();
+      const newProgressPda = PublicKey.findProgramAddressSync(
         [
           Buffer.from("progress"),
           Buffer.from([campaignId]),
-          invalidPlayer.publicKey.toBuffer(),
+          newPlayer.publicKey.toBuffer(),
         ],
         program.programId
       )[0];
 
-      try {
-        await program.methods
-          .getPlayerProgress(campaignId)
-          .accounts({
-            player: invalidPlayer.publicKey,
-            playerProgress: invalidPlayerProgressPda,
-          })
-          .signers([invalidPlayer])
-          .rpc();
-        expect.fail("Should fail with uninitialized player progress");
-      } catch (error: any) {
-        expect(error.error?.errorCode?.code || error.message).to.satisfy(
-          (msg: string) =>
-            msg.includes("AccountNotInitialized") ||
-            msg.includes("Account does not exist")
-        );
-      }
+      const progress = await program.methods
+        .getPlayerProgress(campaignId)
+        .accounts({
+          player: newPlayer.publicKey,
+          playerProgress: newProgressPda,
+        })
+        .view();
+
+      expect(progress.player.toString()).to.equal(
+        newPlayer.publicKey.toString()
+      );
+      expect(progress.campaignId).to.equal(campaignId);
+      expect(Array.from(progress.completedBugs)).to.be.empty;
+      expect(progress.totalCompletedBugs).to.equal(0);
+      expect(progress.totalScore.toNumber()).to.equal(0);
+      expect(progress.masterMintAddress).to.be.null;
     });
   });
 
@@ -559,7 +1837,6 @@ describe("cmpgn", () => {
             player: player.publicKey,
             playerProgress: playerProgressPda,
           })
-          .signers([player])
           .rpc({ commitment: "confirmed" });
 
         const tx = await provider.connection.getTransaction(sig, {
@@ -603,7 +1880,6 @@ describe("cmpgn", () => {
             player: player.publicKey,
             playerProgress: playerProgressPda,
           })
-          .signers([player])
           .rpc({ commitment: "confirmed" });
 
         const tx = await provider.connection.getTransaction(sig, {
@@ -638,34 +1914,107 @@ describe("cmpgn", () => {
       }
     });
 
-    it("fails for an invalid player with no progress", async () => {
-      const invalidPlayer = Keypair.generate();
-      const invalidProgressPda = PublicKey.findProgramAddressSync(
+    it("returns the completion status through simulation", async () => {
+      const completedBug = await program.methods
+        .hasCompletedBug(campaignId, bugId)
+        .accounts({
+          player: player.publicKey,
+          playerProgress: playerProgressPda,
+        })
+        .view();
+
+      expect(completedBug.bugId).to.equal(bugId);
+      expect(completedBug.completed).to.be.true;
+    });
+
+    it("checks several bugs in one call", async () => {
+      const completedBugs = await program.methods
+        .hasCompletedBugs(campaignId, Buffer.from([bugId, 9]))
+        .accounts({
+          player: player.publicKey,
+          playerProgress: playerProgressPda,
+        })
+        .view();
+
+      expect(completedBugs).to.have.lengthOf(2);
+      expect(completedBugs[0].completed).to.be.true;
+      expect(completedBugs[1].bugId).to.equal(9);
+      expect(completedBugs[1].completed).to.be.false;
+    });
+
+    it("reports no completion for a player with no progress", async () => {
+      const newPlayer = Keypair.generate();
+      const newProgressPda = PublicKey.findProgramAddressSync(
         [
           Buffer.from("progress"),
           Buffer.from([campaignId]),
-          invalidPlayer.publicKey.toBuffer(),
+          newPlayer.publicKey.toBuffer(),
         ],
         program.programId
       )[0];
 
-      try {
-        await program.methods
-          .hasCompletedBug(campaignId, bugId)
-          .accounts({
-            player: invalidPlayer.publicKey,
-            playerProgress: invalidProgressPda,
-          })
-          .signers([invalidPlayer])
-          .rpc();
-        expect.fail("Should fail for an invalid player");
-      } catch (error: any) {
-        expect(error.error?.errorCode?.code || error.message).to.satisfy(
-          (msg: string) =>
-            msg.includes("AccountNotInitialized") ||
-            msg.includes("Account does not exist")
-        );
-      }
+      const completedBug = await program.methods
+        .hasCompletedBug(campaignId, bugId)
+        .accounts({
+          player: newPlayer.publicKey,
+          playerProgress: newProgressPda,
+        })
+        .view();
+
+      expect(completedBug.player.toString()).to.equal(
+        newPlayer.publicKey.toString()
+      );
+      expect(completedBug.completed).to.be.false;
+
+      const completedBugs = await program.methods
+        .hasCompletedBugs(campaignId, Buffer.from([bugId, 9]))
+        .accounts({
+          player: newPlayer.publicKey,
+          playerProgress: newProgressPda,
+        })
+        .view();
+
+      expect(completedBugs.map((view) => view.completed)).to.deep.equal([
+        false,
+        false,
+      ]);
+    });
+  });
+
+  describe("Check Completion", () => {
+    it("reports a completed bug", async () => {
+      const status = await program.methods
+        .checkCompletion(campaignId, bugId)
+        .accounts({
+          player: player.publicKey,
+          campaignCompletion: campaignCompletionPda,
+        })
+        .view();
+
+      expect(status).to.deep.equal({ completed: {} });
+    });
+
+    it("reports a bug the player never started", async () => {
+      const unstartedBugId = 15;
+      const unstartedCompletionPda = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("completion"),
+          Buffer.from([campaignId]),
+          player.publicKey.toBuffer(),
+          Buffer.from([unstartedBugId]),
+        ],
+        program.programId
+      )[0];
+
+      const status = await program.methods
+        .checkCompletion(campaignId, unstartedBugId)
+        .accounts({
+          player: player.publicKey,
+          campaignCompletion: unstartedCompletionPda,
+        })
+        .view();
+
+      expect(status).to.deep.equal({ notStarted: {} });
     });
   });
 
@@ -675,7 +2024,6 @@ describe("cmpgn", () => {
         const sig = await program.methods
           .getCampaignStats(campaignId)
           .accounts({
-            gameAuthority: gameAuthority.publicKey,
             campaign: campaignPda,
           })
           .rpc({ commitment: "confirmed" });
@@ -720,7 +2068,6 @@ describe("cmpgn", () => {
         await program.methods
           .getCampaignStats(invalidCampaignId)
           .accounts({
-            gameAuthority: gameAuthority.publicKey,
             campaign: invalidCampaignPda,
           })
           .rpc();
@@ -734,22 +2081,39 @@ describe("cmpgn", () => {
       }
     });
 
-    it("fails to get the campaign stats with an invalid game authority", async () => {
-      const invalidGameAuthority = Keypair.generate();
-      try {
-        const sig = await program.methods
-          .getCampaignStats(campaignId)
-          .accounts({
-            gameAuthority: invalidGameAuthority.publicKey,
-            campaign: campaignPda,
-          })
-          .signers([invalidGameAuthority])
-          .rpc({ commitment: "confirmed" });
+    it("gets the campaign stats without a signer", async () => {
+      const instruction = await program.methods
+        .getCampaignStats(campaignId)
+        .accounts({
+          campaign: campaignPda,
+        })
+        .instruction();
 
-        expect.fail("Should fail with invalid game authority");
-      } catch (error: any) {
-        expect(error.error?.errorCode?.code).to.equal("ConstraintHasOne");
-      }
+      expect(instruction.keys.some((key) => key.isSigner)).to.be.false;
+
+      const stats = await program.methods
+        .getCampaignStats(campaignId)
+        .accounts({
+          campaign: campaignPda,
+        })
+        .view();
+
+      expect(stats.campaignId).to.equal(campaignId);
+    });
+
+    it("returns the campaign stats through simulation", async () => {
+      const stats = await program.methods
+        .getCampaignStats(campaignId)
+        .accounts({
+          campaign: campaignPda,
+        })
+        .view();
+
+      const campaignAccount = await program.account.campaign.fetch(campaignPda);
+
+      expect(stats.campaignId).to.equal(campaignId);
+      expect(stats.totalCompletions).to.equal(campaignAccount.totalCompletions);
+      expect(stats.bugSolvers).to.deep.equal(campaignAccount.bugSolvers);
     });
   });
 
@@ -757,10 +2121,6 @@ describe("cmpgn", () => {
     it("gets the daily bug for a player", async () => {
       const sig = await program.methods
         .getDailyBug()
-        .accounts({
-          player: player.publicKey,
-        })
-        .signers([player])
         .rpc({ commitment: "confirmed" });
 
       const tx = await provider.connection.getTransaction(sig, {
@@ -779,5 +2139,83 @@ describe("cmpgn", () => {
       expect(events[0].name).to.equal("dailyBugEvent");
       expect(events[0].data.bugId).to.be.within(1, 20);
     });
+
+    it("returns the daily bug through simulation", async () => {
+      const dailyBugId = await program.methods.getDailyBug().view();
+
+      expect(dailyBugId).to.be.within(1, 20);
+    });
+  });
+
+  describe("Revoke Completion", () => {
+    it("fails to revoke a completion with an unauthorized signer", async () => {
+      try {
+        const sig = await program.methods
+          .revokeCompletion(campaignId, bugId)
+          .accounts({
+            gameAuthority: unauthorizedUser.publicKey,
+            player: player.publicKey,
+            campaign: campaignPda,
+            campaignCompletion: campaignCompletionPda,
+            playerProgress: playerProgressPda,
+            asset: asset.publicKey,
+            collection: collection.publicKey,
+            collectionAuthority: collectionAuthorityPda,
+            coreProgram: MPL_CORE_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([unauthorizedUser])
+          .rpc();
+        expect.fail("Should fail with invalid game authority");
+      } catch (error: any) {
+        expect(error.error?.errorCode?.code).to.equal("ConstraintHasOne");
+      }
+    });
+
+    it("revokes a completion and burns its NFT", async () => {
+      const campaignBefore = await program.account.campaign.fetch(campaignPda);
+
+      try {
+        const sig = await program.methods
+          .revokeCompletion(campaignId, bugId)
+          .accounts({
+            gameAuthority: gameAuthority.publicKey,
+            player: player.publicKey,
+            campaign: campaignPda,
+            campaignCompletion: campaignCompletionPda,
+            playerProgress: playerProgressPda,
+            asset: asset.publicKey,
+            collection: collection.publicKey,
+            collectionAuthority: collectionAuthorityPda,
+            coreProgram: MPL_CORE_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      } catch (error: any) {
+        console.error(`something went wrong: ${error}`);
+        if (error.logs && Array.isArray(error.logs)) {
+          console.log("Transaction Logs:");
+          error.logs.forEach((log: string) => console.log(log));
+        } else {
+          console.log("No logs available in the error.");
+        }
+        throw error;
+      }
+
+      const campaignCompletion = await program.account.campaignCompletion.fetch(
+        campaignCompletionPda
+      );
+      const playerProgress = await program.account.playerProgress.fetch(
+        playerProgressPda
+      );
+      const campaign = await program.account.campaign.fetch(campaignPda);
+
+      expect(campaignCompletion.revoked).to.be.true;
+      expect(campaignCompletion.nftMintAddress).to.be.null;
+      expect(Array.from(playerProgress.completedBugs)).to.not.include(bugId);
+      expect(campaign.bugSolvers[bugId - 1]).to.equal(
+        campaignBefore.bugSolvers[bugId - 1]
+      );
+    });
   });
 });